@@ -0,0 +1,61 @@
+//! Benchmarks every registered day on its committed input, or only the days
+//! given as arguments: `cargo bench -- 5 2022/11`. A day without a year is
+//! benchmarked in every year that has it.
+
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+
+use aoc_2021::bench::{self, Options};
+use aoc_2021::config::Config;
+
+fn main() -> ExitCode {
+    // cargo passes `--bench`; anything else that isn't a day is a mistake.
+    let mut days = Vec::new();
+    for arg in env::args().skip(1).filter(|arg| !arg.starts_with("--")) {
+        let (year, day) = match arg.split_once('/') {
+            Some((year, day)) => (year.parse::<u16>().ok(), day),
+            None => (None, arg.as_str()),
+        };
+        let registered = aoc_2021::days().any(|solver| {
+            year.is_none_or(|y| y == solver.year()) && day.parse() == Ok(solver.day())
+        });
+        match day.parse::<u8>() {
+            Ok(day) if registered => days.push((year, day)),
+            _ => {
+                eprintln!("error: {} is not a registered day", arg);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let options = Options::default();
+    let config = Config::default();
+    for solver in aoc_2021::days() {
+        let selected = days
+            .iter()
+            .any(|&(year, day)| year.is_none_or(|y| y == solver.year()) && day == solver.day());
+        if !days.is_empty() && !selected {
+            continue;
+        }
+
+        let path = config.input_path(solver.year(), solver.day());
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("error: {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        match bench::bench_day(solver, &input, &options) {
+            Ok(report) => print!("{}", report),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,10 @@
+//! Feeds arbitrary bytes to every day's parser and solver. The first byte
+//! picks the day. Run with `cargo +nightly fuzz run days`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    aoc_2021::fuzz::check_any(data);
+});
@@ -0,0 +1,235 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The result of solving one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    Bitmap(Bitmap),
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Self::Unsolved)
+    }
+
+    /// Whether the answer needs more than one line to display.
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Self::Bitmap(_) => true,
+            Self::Text(text) => text.contains('\n'),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{}", n),
+            Self::Text(text) => write!(f, "{}", text),
+            Self::Bitmap(bitmap) => write!(f, "{}", bitmap),
+            Self::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl PartialEq<i64> for Answer {
+    fn eq(&self, other: &i64) -> bool {
+        matches!(self, Self::Integer(n) if n == other)
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Self::Unsolved => false,
+            Self::Text(text) => text == other,
+            answer => format!("{}", answer) == other,
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// Integers too large for an `i64` are kept as text, which still compares
+/// equal to the number written out.
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Self::Integer(n),
+                        Err(_) => Self::Text(n.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<Bitmap> for Answer {
+    fn from(bitmap: Bitmap) -> Self {
+        Self::Bitmap(bitmap)
+    }
+}
+
+/// A monochrome picture, such as a CRT frame, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "Vec<String>", try_from = "Vec<String>")]
+pub struct Bitmap {
+    width: usize,
+    pixels: Vec<bool>,
+}
+
+impl Bitmap {
+    pub fn new(width: usize, pixels: Vec<bool>) -> Self {
+        assert!(width > 0 && pixels.len().is_multiple_of(width));
+        Self { width, pixels }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.len() / self.width
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        if x < self.width {
+            self.pixels.get(y * self.width + x).copied()
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        self.pixels.chunks(self.width)
+    }
+}
+
+impl fmt::Display for Bitmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for &pixel in row {
+                write!(f, "{}", if pixel { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Bitmap> for Vec<String> {
+    fn from(bitmap: Bitmap) -> Self {
+        bitmap.to_string().lines().map(str::to_string).collect()
+    }
+}
+
+impl TryFrom<Vec<String>> for Bitmap {
+    type Error = String;
+
+    fn try_from(rows: Vec<String>) -> Result<Self, Self::Error> {
+        let width = rows.first().map(String::len).unwrap_or_default();
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return Err("bitmap rows must be non-empty and of equal width".to_string());
+        }
+
+        let pixels = rows
+            .iter()
+            .flat_map(|row| row.chars())
+            .map(|c| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                c => Err(format!("unexpected bitmap pixel '{}'", c)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { width, pixels })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bitmap() -> Bitmap {
+        Bitmap::new(3, vec![true, false, true, false, true, false])
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::from(bitmap()).to_string(), "#.#\n.#.\n");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn test_from_large_integer() {
+        let answer = Answer::from(u64::MAX);
+
+        assert_eq!(answer, Answer::Text("18446744073709551615".to_string()));
+        assert_eq!(answer, "18446744073709551615");
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Answer::Integer(-3), -3);
+        assert_eq!(Answer::Integer(24000), "24000");
+        assert_eq!(Answer::from("MCD"), "MCD");
+        assert_eq!(Answer::from(bitmap()), "#.#\n.#.\n");
+        assert_ne!(Answer::Unsolved, "unsolved");
+        assert_ne!(Answer::from("7"), 7);
+    }
+
+    #[test]
+    fn test_json() {
+        let answers = vec![
+            Answer::Integer(13140),
+            Answer::from("CMZ"),
+            Answer::from(bitmap()),
+            Answer::Unsolved,
+        ];
+
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(
+            json,
+            r##"[{"type":"integer","value":13140},{"type":"text","value":"CMZ"},{"type":"bitmap","value":["#.#",".#."]},{"type":"unsolved"}]"##
+        );
+
+        let parsed: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, answers);
+    }
+
+    #[test]
+    fn test_bitmap_from_invalid_rows() {
+        assert!(Bitmap::try_from(vec!["#.".to_string(), "#".to_string()]).is_err());
+        assert!(Bitmap::try_from(vec!["#x".to_string()]).is_err());
+        assert!(Bitmap::try_from(Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::config;
+use crate::config::DEFAULT_PROFILE;
+use crate::solution::Part;
+
+/// A confirmed answer, as written in `answers.toml`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Integer(i64),
+    Text(String),
+}
+
+impl Expected {
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match self {
+            Self::Integer(n) => answer == n,
+            Self::Text(text) => answer == text.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{}", n),
+            Self::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayAnswers {
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+    /// Answers for the day's other input profiles, from
+    /// `[YYYY.NN.profiles.<name>]` tables.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, DayAnswers>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, part: Part) -> Option<&Expected> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// The known answers for the committed inputs, keyed by year and day, as in
+/// `[2022.01]`. These are the answers for the default input profile.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KnownAnswers(BTreeMap<(u16, u8), DayAnswers>);
+
+impl KnownAnswers {
+    pub fn default_path() -> PathBuf {
+        config::default_root().join("answers.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let years: BTreeMap<String, BTreeMap<String, DayAnswers>> =
+            toml::from_str(text).map_err(|e| e.to_string())?;
+
+        let mut known = BTreeMap::new();
+        for (year_key, days) in years {
+            let year = year_key
+                .parse::<u16>()
+                .ok()
+                .filter(|year| *year >= 2015)
+                .ok_or_else(|| format!("{:?} is not a year", year_key))?;
+            for (key, answers) in days {
+                if let Some((name, _)) = answers.profiles.iter().find(|(name, profile)| {
+                    *name == DEFAULT_PROFILE || !profile.profiles.is_empty()
+                }) {
+                    return Err(format!("{:?} is not a profile of {}.{}", name, year, key));
+                }
+                match key.parse::<u8>() {
+                    Ok(day) if (1..=25).contains(&day) => known.insert((year, day), answers),
+                    _ => return Err(format!("{:?} is not a day", key)),
+                };
+            }
+        }
+        Ok(Self(known))
+    }
+
+    pub fn day(&self, year: u16, day: u8) -> Option<&DayAnswers> {
+        self.0.get(&(year, day))
+    }
+
+    /// The answers for one of the day's input profiles.
+    pub fn profile(&self, year: u16, day: u8, profile: &str) -> Option<&DayAnswers> {
+        let answers = self.day(year, day)?;
+        match profile {
+            DEFAULT_PROFILE => Some(answers),
+            _ => answers.profiles.get(profile),
+        }
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Expected> {
+        self.day(year, day).and_then(|answers| answers.get(part))
+    }
+
+    /// The years and days that have answers, in order.
+    pub fn days(&self) -> impl Iterator<Item = (u16, u8)> + '_ {
+        self.0.keys().copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answer::Bitmap;
+
+    const ANSWERS: &str = r#"
+[2022.01]
+part1 = 71502
+
+[2022.01.profiles.alice]
+part1 = 69836
+part2 = 207968
+
+[2022.05]
+part1 = "TWSGQHNHL"
+part2 = """
+#.
+.#
+"""
+"#;
+
+    #[test]
+    fn test_parse() {
+        let answers = KnownAnswers::parse(ANSWERS).unwrap();
+
+        assert_eq!(
+            answers.days().collect::<Vec<_>>(),
+            vec![(2022, 1), (2022, 5)]
+        );
+        assert_eq!(
+            answers.get(2022, 1, Part::One),
+            Some(&Expected::Integer(71502))
+        );
+        assert_eq!(answers.get(2022, 1, Part::Two), None);
+        assert_eq!(answers.get(2022, 2, Part::One), None);
+        assert_eq!(answers.get(2021, 1, Part::One), None);
+    }
+
+    #[test]
+    fn test_profiles() {
+        let answers = KnownAnswers::parse(ANSWERS).unwrap();
+        let alice = answers.profile(2022, 1, "alice").unwrap();
+
+        assert_eq!(alice.get(Part::Two), Some(&Expected::Integer(207968)));
+        assert_eq!(
+            answers.profile(2022, 1, DEFAULT_PROFILE),
+            answers.day(2022, 1)
+        );
+        assert_eq!(answers.profile(2022, 1, "bob"), None);
+        assert_eq!(answers.profile(2022, 5, "alice"), None);
+
+        let error = KnownAnswers::parse("[2022.01.profiles.default]\npart1 = 1\n").unwrap_err();
+        assert_eq!(error, "\"default\" is not a profile of 2022.01");
+    }
+
+    #[test]
+    fn test_matches() {
+        let answers = KnownAnswers::parse(ANSWERS).unwrap();
+        let bitmap = Bitmap::new(2, vec![true, false, false, true]);
+
+        assert!(answers
+            .get(2022, 1, Part::One)
+            .unwrap()
+            .matches(&71502.into()));
+        assert!(!answers
+            .get(2022, 1, Part::One)
+            .unwrap()
+            .matches(&71503.into()));
+        assert!(answers
+            .get(2022, 5, Part::One)
+            .unwrap()
+            .matches(&"TWSGQHNHL".into()));
+        assert!(answers
+            .get(2022, 5, Part::Two)
+            .unwrap()
+            .matches(&bitmap.into()));
+        assert!(!Expected::Text("unsolved".into()).matches(&Answer::Unsolved));
+    }
+
+    #[test]
+    fn test_rejects_bad_days() {
+        assert!(KnownAnswers::parse("[2022.26]\npart1 = 1\n").is_err());
+        assert!(KnownAnswers::parse("[2022.01]\npart3 = 1\n").is_err());
+        assert!(KnownAnswers::parse("[01]\npart1 = 1\n").is_err());
+    }
+}
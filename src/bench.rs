@@ -0,0 +1,419 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::DayError;
+use crate::solution::{Part, Solver, Stage};
+
+/// How long to run each measurement for.
+#[derive(Copy, Clone, Debug)]
+pub struct Options {
+    pub warmup: Duration,
+    pub measure: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(200),
+            measure: Duration::from_secs(1),
+            min_samples: 5,
+            max_samples: 10_000,
+        }
+    }
+}
+
+/// Durations are stored as whole nanoseconds in reports.
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+/// Summary statistics over the samples of one measurement.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "stddev_ns", with = "nanos")]
+    pub stddev: Duration,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "max_ns", with = "nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let n = samples.len() as f64;
+        let nanos = samples.iter().map(|d| d.as_nanos() as f64);
+        let mean = nanos.clone().sum::<f64>() / n;
+        let variance = if samples.len() > 1 {
+            nanos.map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            samples: samples.len(),
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            min: *samples.iter().min().unwrap(),
+            max: *samples.iter().max().unwrap(),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>10.2?} ± {:>9.2?}  ({} samples)",
+            self.mean, self.stddev, self.samples
+        )
+    }
+}
+
+/// Times `f` repeatedly: first for `options.warmup`, then collecting samples
+/// until `options.measure` has passed and at least `min_samples` were taken.
+pub fn measure<T>(options: &Options, mut f: impl FnMut() -> T) -> Stats {
+    let start = Instant::now();
+    while start.elapsed() < options.warmup {
+        black_box(f());
+    }
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.len() < options.max_samples
+        && (samples.len() < options.min_samples || start.elapsed() < options.measure)
+    {
+        let sample = Instant::now();
+        black_box(f());
+        samples.push(sample.elapsed());
+    }
+
+    Stats::from_samples(&samples)
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PartBench {
+    pub part: Part,
+    pub stats: Stats,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub title: String,
+    pub parse: Stats,
+    pub parts: Vec<PartBench>,
+}
+
+/// Benchmarks parsing `input` and solving each part from the parsed input,
+/// so that neither I/O nor parsing is counted in the part timings.
+pub fn bench_day(
+    solver: &dyn Solver,
+    input: &str,
+    options: &Options,
+) -> Result<DayBench, DayError> {
+    let parsed = solver.parse(input)?;
+    for part in Part::ALL {
+        parsed.solve(part)?;
+    }
+
+    let parse = measure(options, || solver.parse(input));
+    let parts = Part::ALL
+        .into_iter()
+        .map(|part| PartBench {
+            part,
+            stats: measure(options, || parsed.solve(part)),
+        })
+        .collect();
+
+    Ok(DayBench {
+        year: solver.year(),
+        day: solver.day(),
+        title: solver.title().to_string(),
+        parse,
+        parts,
+    })
+}
+
+impl DayBench {
+    pub fn stats(&self, stage: Stage) -> Option<&Stats> {
+        match stage {
+            Stage::Parse => Some(&self.parse),
+            Stage::Part(part) => self.parts.iter().find(|p| p.part == part).map(|p| &p.stats),
+        }
+    }
+}
+
+impl fmt::Display for DayBench {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Day {:02}: {}", self.day, self.title)?;
+        writeln!(f, "  parse  {}", self.parse)?;
+        for part in &self.parts {
+            writeln!(f, "  part {} {}", part.part, part.stats)?;
+        }
+        Ok(())
+    }
+}
+
+/// A timing that got slower than its baseline by more than the threshold.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// The slowdown as a percentage of the baseline.
+    pub fn change(&self) -> f64 {
+        change(self.baseline, self.current)
+    }
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {:02} {}: {:.2?} -> {:.2?} ({:+.1}%)",
+            self.year,
+            self.day,
+            self.stage,
+            self.baseline,
+            self.current,
+            self.change()
+        )
+    }
+}
+
+fn change(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_nanos() as f64;
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current.as_nanos() as f64 - baseline) / baseline * 100.0
+}
+
+/// The benchmarks of a whole run, as saved to and loaded from JSON.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub days: Vec<DayBench>,
+}
+
+impl BenchReport {
+    pub fn day(&self, year: u16, day: u8) -> Option<&DayBench> {
+        self.days.iter().find(|d| (d.year, d.day) == (year, day))
+    }
+
+    /// Timings more than `threshold` percent slower than in `baseline`. Days
+    /// and stages missing from the baseline are not compared.
+    pub fn regressions(&self, baseline: &BenchReport, threshold: f64) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+        for day in &self.days {
+            let Some(old) = baseline.day(day.year, day.day) else {
+                continue;
+            };
+            for stage in Stage::ALL {
+                let (Some(new), Some(old)) = (day.stats(stage), old.stats(stage)) else {
+                    continue;
+                };
+                if change(old.mean, new.mean) > threshold {
+                    regressions.push(Regression {
+                        year: day.year,
+                        day: day.day,
+                        stage,
+                        baseline: old.mean,
+                        current: new.mean,
+                    });
+                }
+            }
+        }
+        regressions
+    }
+
+    /// A Markdown table of mean ± stddev per day and stage, with the change
+    /// against `baseline` when there is one. Regressions beyond `threshold`
+    /// are marked in bold.
+    pub fn to_markdown(&self, baseline: Option<&BenchReport>, threshold: f64) -> String {
+        let mut table = String::from("| Year | Day | Title | Parse | Part 1 | Part 2 |\n");
+        table.push_str("| ---: | ---: | --- | ---: | ---: | ---: |\n");
+
+        for day in &self.days {
+            table.push_str(&format!(
+                "| {} | {:02} | {} |",
+                day.year, day.day, day.title
+            ));
+            for stage in Stage::ALL {
+                let Some(stats) = day.stats(stage) else {
+                    table.push_str(" - |");
+                    continue;
+                };
+                table.push_str(&format!(" {:.2?} ± {:.2?}", stats.mean, stats.stddev));
+
+                let old = baseline
+                    .and_then(|baseline| baseline.day(day.year, day.day))
+                    .and_then(|old| old.stats(stage));
+                if let Some(old) = old {
+                    let change = change(old.mean, stats.mean);
+                    if change > threshold {
+                        table.push_str(&format!(" **({:+.1}%)**", change));
+                    } else {
+                        table.push_str(&format!(" ({:+.1}%)", change));
+                    }
+                }
+                table.push_str(" |");
+            }
+            table.push('\n');
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_micros);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2_138));
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.max, Duration::from_micros(9));
+        assert_eq!(Stats::from_samples(&[]), Stats::default());
+    }
+
+    #[test]
+    fn test_measure_bounds() {
+        let options = Options {
+            warmup: Duration::ZERO,
+            measure: Duration::ZERO,
+            min_samples: 3,
+            max_samples: 10,
+        };
+
+        assert_eq!(measure(&options, || 1).samples, 3);
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let options = Options {
+            warmup: Duration::ZERO,
+            measure: Duration::ZERO,
+            min_samples: 2,
+            max_samples: 2,
+        };
+        let solver = crate::find(2022, 1).unwrap();
+        let bench = bench_day(solver, "1000\n2000\n\n4000\n", &options).unwrap();
+
+        assert_eq!(bench.parse.samples, 2);
+        assert_eq!(bench.parts.len(), 2);
+        assert!(bench.to_string().starts_with("Day 01: Calorie Counting\n"));
+        assert!(bench_day(solver, "x", &options).is_err());
+    }
+
+    fn stats(micros: u64) -> Stats {
+        Stats {
+            samples: 10,
+            mean: Duration::from_micros(micros),
+            ..Stats::default()
+        }
+    }
+
+    fn report(parse: u64, part1: u64, part2: u64) -> BenchReport {
+        BenchReport {
+            days: vec![DayBench {
+                year: 2022,
+                day: 15,
+                title: "Beacon Exclusion Zone".to_string(),
+                parse: stats(parse),
+                parts: vec![
+                    PartBench {
+                        part: Part::One,
+                        stats: stats(part1),
+                    },
+                    PartBench {
+                        part: Part::Two,
+                        stats: stats(part2),
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = report(100, 1000, 50);
+        let current = report(105, 1500, 20);
+
+        let regressions = current.regressions(&baseline, 10.0);
+
+        assert_eq!(
+            regressions,
+            vec![Regression {
+                year: 2022,
+                day: 15,
+                stage: Stage::Part(Part::One),
+                baseline: Duration::from_micros(1000),
+                current: Duration::from_micros(1500),
+            }]
+        );
+        assert_eq!(
+            regressions[0].to_string(),
+            "2022 day 15 part 1: 1.00ms -> 1.50ms (+50.0%)"
+        );
+        assert!(current
+            .regressions(&BenchReport::default(), 10.0)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_markdown() {
+        let baseline = report(100, 1000, 50);
+        let current = report(105, 1500, 20);
+
+        assert_eq!(
+            current.to_markdown(Some(&baseline), 10.0),
+            "| Year | Day | Title | Parse | Part 1 | Part 2 |
+| ---: | ---: | --- | ---: | ---: | ---: |
+| 2022 | 15 | Beacon Exclusion Zone | 105.00µs ± 0.00ns (+5.0%) | 1.50ms ± 0.00ns **(+50.0%)** | 20.00µs ± 0.00ns (-60.0%) |
+"
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let report = report(100, 1000, 50);
+        let json = serde_json::to_string(&report).unwrap();
+
+        assert!(json.contains("\"mean_ns\":100000"));
+        assert_eq!(serde_json::from_str::<BenchReport>(&json).unwrap(), report);
+    }
+}
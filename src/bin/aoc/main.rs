@@ -0,0 +1,761 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use clap::{Args, Parser, Subcommand};
+
+use aoc_2021::answers::KnownAnswers;
+use aoc_2021::bench::{self, BenchReport, Options};
+use aoc_2021::config::Config;
+use aoc_2021::examples;
+use aoc_2021::fetch::{self, Fetched};
+use aoc_2021::generate;
+use aoc_2021::http::Client;
+use aoc_2021::input::Normalized;
+use aoc_2021::leaderboard::{Leaderboard, Scoring};
+use aoc_2021::params::Overrides;
+use aoc_2021::progress::RunProgress;
+use aoc_2021::puzzle;
+use aoc_2021::runner::{self, InputSource, Job, ProfileReport};
+use aoc_2021::scaffold;
+use aoc_2021::solution::{Part, Solver};
+use aoc_2021::submit::{self, History};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every registered day with --all
+    Run(RunArgs),
+    /// Download a day's puzzle input into input/
+    Fetch(FetchArgs),
+    /// Solve one part and submit the answer
+    Submit(SubmitArgs),
+    /// Create and register the module for a new day
+    New(NewArgs),
+    /// Benchmark days and compare the timings against a saved baseline
+    Bench(BenchArgs),
+    /// Generate a synthetic puzzle input
+    Gen(GenArgs),
+    /// Show the parameters a day would be solved with
+    Params(ParamsArgs),
+    /// Show a day's puzzle description, downloading it if it isn't cached
+    Puzzle(PuzzleArgs),
+    /// Extract the examples from a day's cached puzzle into fixtures/
+    Examples(ExamplesArgs),
+    /// Rank the members of a private leaderboard saved as JSON
+    Leaderboard(LeaderboardArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Solve every registered day of the year
+    #[arg(long)]
+    all: bool,
+
+    /// Event year, if not the one in aoc.toml
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Only solve this part (1 or 2)
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Read the input from this file, or from stdin with `-`
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Read the input of this profile, from input/<year>/NN/<profile>.txt
+    #[arg(long, conflicts_with_all = ["all", "input"])]
+    profile: Option<String>,
+
+    /// Solve every input profile and check the answers against answers.toml
+    #[arg(long, conflicts_with_all = ["input", "profile", "stream", "json"])]
+    profiles: bool,
+
+    /// Print one JSON report per day instead of text
+    #[arg(long)]
+    json: bool,
+
+    /// Override one of the day's parameters, as key=value
+    #[arg(long = "param", value_name = "KEY=VALUE", conflicts_with = "all")]
+    params: Vec<String>,
+
+    /// Solve from the input as it's read instead of loading it first, for
+    /// inputs too large to fit in memory
+    #[arg(long, conflicts_with = "all")]
+    stream: bool,
+
+    /// Number of days to solve at once with --all [default: available cores]
+    #[arg(long, requires = "all")]
+    jobs: Option<usize>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Event year
+    year: u16,
+
+    /// Day to fetch
+    day: u8,
+
+    /// Download again even if the input is already cached
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit
+    day: u8,
+
+    /// Part to submit (1 or 2)
+    part: Part,
+
+    /// Event year, if not the one in aoc.toml
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Read the input from this file, or from stdin with `-`
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Override one of the day's parameters, as key=value
+    #[arg(long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to start
+    day: u8,
+
+    /// Puzzle title
+    #[arg(long)]
+    title: Option<String>,
+
+    /// Also download the day's input
+    #[arg(long)]
+    fetch: bool,
+
+    /// Event year, if not the one in aoc.toml
+    #[arg(long)]
+    year: Option<u16>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Days to benchmark; all registered days of the year if none are given
+    days: Vec<u8>,
+
+    /// Event year, if not the one in aoc.toml
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Directory for report.md and report.json [default: target/aoc-bench/<year>]
+    #[arg(long)]
+    out: Option<PathBuf>,
+
+    /// Baseline to compare against [default: <out>/baseline.json]
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Save this run as the new baseline
+    #[arg(long)]
+    save_baseline: bool,
+
+    /// Slowdown in percent that counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Take fewer samples
+    #[arg(long)]
+    quick: bool,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to generate an input for
+    day: u8,
+
+    /// Event year, if not the one in aoc.toml
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Roughly how many lines or records to generate
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+
+    /// Seed for the generator; the same seed gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Write the input to a file instead of stdout
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ParamsArgs {
+    /// Day to show
+    day: u8,
+
+    /// Event year, if not the one in aoc.toml
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Override one of the day's parameters, as key=value
+    #[arg(long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+}
+
+#[derive(Args)]
+struct PuzzleArgs {
+    /// Day to show
+    day: u8,
+
+    /// Event year, if not the one in aoc.toml
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Download the page again, such as once part two is unlocked
+    #[arg(long)]
+    force: bool,
+
+    /// Store this page, saved from a browser, instead of downloading it
+    #[arg(long, conflicts_with = "force")]
+    from: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ExamplesArgs {
+    /// Day to extract the examples of
+    day: u8,
+
+    /// Event year, if not the one in aoc.toml
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Replace the day's existing examples file
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct LeaderboardArgs {
+    /// The leaderboard's JSON, as saved from the site
+    file: PathBuf,
+
+    /// How to rank the members: local, stars or time
+    #[arg(long, default_value = "local")]
+    scoring: Scoring,
+
+    /// Show each member's times for this day instead of the ranking
+    #[arg(long)]
+    day: Option<u8>,
+}
+
+/// Finds `day` of `year`, or explains that it hasn't been solved.
+fn find(year: u16, day: u8) -> Result<&'static dyn Solver, String> {
+    aoc_2021::find(year, day).ok_or(format!("day {} of {} is not implemented", day, year))
+}
+
+/// The parameter overrides for `day` of `year`: those in aoc.toml, then any
+/// given with --param.
+fn overrides(config: &Config, year: u16, day: u8, args: &[String]) -> Result<Overrides, String> {
+    let mut params = config.params(year, day);
+    for arg in args {
+        params.set_arg(arg)?;
+    }
+    Ok(params)
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let year = args.year.unwrap_or(config.year);
+    let solvers = if args.all {
+        aoc_2021::days_in(year).to_vec()
+    } else {
+        let day = args.day.expect("day is required without --all");
+        vec![find(year, day)?]
+    };
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let threads = args.jobs.unwrap_or_else(|| {
+        thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    });
+    if args.profiles {
+        return run_profiles(&config, year, &solvers, &parts, &args.params, threads);
+    }
+
+    let source = match args.profile {
+        Some(profile) => InputSource::Profile(profile),
+        None => InputSource::from_arg(args.input.as_deref()),
+    };
+    if args.stream {
+        let params = overrides(&config, year, solvers[0].day(), &args.params)?;
+        return run_stream(solvers[0], &config, &source, &parts, &params, args.json);
+    }
+    let jobs = solvers
+        .iter()
+        .map(|&solver| {
+            let day = solver.day();
+            Ok(Job {
+                solver,
+                input: source.read(&config, year, day).map_err(|e| e.to_string())?,
+                params: overrides(&config, year, day, &args.params)?,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    for job in &jobs {
+        let normalized = Normalized::new(&job.input);
+        let fixes = normalized
+            .fixes()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if !fixes.is_empty() {
+            eprintln!(
+                "note: day {:02} input: {}",
+                job.solver.day(),
+                fixes.join(", ")
+            );
+        }
+    }
+
+    let results = if args.json || jobs.len() == 1 {
+        runner::run_all(&jobs, &parts, threads, &|_| {})
+    } else {
+        let progress = RunProgress::new(&solvers, parts.len());
+        let results = runner::run_all(&jobs, &parts, threads, &|event| progress.handle(event));
+        progress.clear();
+        results
+    };
+
+    let mut reports = Vec::new();
+    let mut errors = Vec::new();
+    for result in results {
+        match result {
+            Ok(report) => reports.push(report),
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+
+    if args.json {
+        for report in &reports {
+            let json = serde_json::to_string(report).map_err(|e| e.to_string())?;
+            println!("{}", json);
+        }
+    } else if reports.len() == 1 {
+        print!("{}", reports[0]);
+    } else if !reports.is_empty() {
+        print!("{}", runner::summary(&reports));
+    }
+
+    match errors.len() {
+        0 => Ok(()),
+        _ => Err(errors.join("\n")),
+    }
+}
+
+/// Solves every input profile of each of `solvers` and prints how the answers
+/// compare with the ones in answers.toml.
+fn run_profiles(
+    config: &Config,
+    year: u16,
+    solvers: &[&dyn Solver],
+    parts: &[Part],
+    params: &[String],
+    threads: usize,
+) -> Result<(), String> {
+    let known = KnownAnswers::load(&KnownAnswers::default_path())?;
+
+    let mut profiles = Vec::new();
+    let mut jobs = Vec::new();
+    for &solver in solvers {
+        let day = solver.day();
+        for profile in runner::profiles(config, year, day).map_err(|e| e.to_string())? {
+            let input = InputSource::Profile(profile.clone())
+                .read(config, year, day)
+                .map_err(|e| e.to_string())?;
+            jobs.push(Job {
+                solver,
+                input,
+                params: overrides(config, year, day, params)?,
+            });
+            profiles.push(profile);
+        }
+    }
+    if jobs.is_empty() {
+        return Err(format!("no inputs under input/{}", year));
+    }
+
+    let mut reports = Vec::new();
+    let mut errors = Vec::new();
+    let results = runner::run_all(&jobs, parts, threads, &|_| {});
+    for (profile, result) in profiles.into_iter().zip(results) {
+        match result {
+            Ok(report) => reports.push(ProfileReport { profile, report }),
+            Err(e) => errors.push(format!("{} profile: {}", profile, e)),
+        }
+    }
+
+    let (table, disagreements) = runner::cross_check(&reports, &known);
+    print!("{}", table);
+    if disagreements > 0 {
+        errors.push(format!(
+            "{} answers disagree with answers.toml",
+            disagreements
+        ));
+    }
+
+    match errors.len() {
+        0 => Ok(()),
+        _ => Err(errors.join("\n")),
+    }
+}
+
+fn run_stream(
+    solver: &dyn Solver,
+    config: &Config,
+    source: &InputSource,
+    parts: &[Part],
+    params: &Overrides,
+    json: bool,
+) -> Result<(), String> {
+    if *source == InputSource::Stdin && parts.len() > 1 {
+        return Err("stdin can only be streamed once, so pick a --part".to_string());
+    }
+
+    let report =
+        runner::run_stream(solver, config, source, parts, params).map_err(|e| e.to_string())?;
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string(&report).map_err(|e| e.to_string())?
+        );
+    } else {
+        print!("{}", report);
+    }
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let client = Client::new(&config);
+
+    match fetch::fetch_input(&client, &config, args.year, args.day, args.force)
+        .map_err(|e| e.to_string())?
+    {
+        Fetched::Cached(path) => println!("{} is already cached", path.display()),
+        Fetched::Downloaded(path) => println!("Saved {}", path.display()),
+    }
+
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let year = args.year.unwrap_or(config.year);
+    let solver = find(year, args.day)?;
+
+    let input = InputSource::from_arg(args.input.as_deref())
+        .read(&config, year, args.day)
+        .map_err(|e| e.to_string())?;
+    let params = overrides(&config, year, args.day, &args.params)?;
+    let parsed = solver
+        .parse_with(&input, &params)
+        .map_err(|e| e.to_string())?;
+    let answer = parsed.solve(args.part).map_err(|e| e.to_string())?;
+    println!("Day {:02} part {}: {}", args.day, args.part, answer);
+
+    let client = Client::new(&config);
+    let path = config.history_path();
+    let mut history = History::load(&path).map_err(|e| e.to_string())?;
+    let result = submit::submit(
+        &client,
+        &mut history,
+        year,
+        args.day,
+        args.part,
+        &answer,
+        SystemTime::now(),
+    );
+    history.save(&path).map_err(|e| e.to_string())?;
+
+    println!("{}", result.map_err(|e| e.to_string())?);
+    Ok(())
+}
+
+fn new(args: NewArgs) -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let year = args.year.unwrap_or(config.year);
+    let title = args.title.unwrap_or_else(|| format!("Day {}", args.day));
+
+    for step in
+        scaffold::scaffold(&config.root, year, args.day, &title).map_err(|e| e.to_string())?
+    {
+        println!("{}", step);
+    }
+
+    if args.fetch {
+        fetch(FetchArgs {
+            year,
+            day: args.day,
+            force: false,
+        })?;
+
+        let client = Client::new(&config);
+        fetch::fetch_puzzle(&client, &config, year, args.day, false).map_err(|e| e.to_string())?;
+        if !config.examples_path(year, args.day).exists() {
+            examples(ExamplesArgs {
+                day: args.day,
+                year: Some(year),
+                force: false,
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    if cfg!(debug_assertions) {
+        eprintln!("warning: benchmarking a debug build, use `cargo run --release`");
+    }
+
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let year = args.year.unwrap_or(config.year);
+    let out = args
+        .out
+        .unwrap_or_else(|| config.root.join("target/aoc-bench").join(year.to_string()));
+    let baseline_path = args.baseline.unwrap_or_else(|| out.join("baseline.json"));
+
+    let solvers = if args.days.is_empty() {
+        aoc_2021::days_in(year).to_vec()
+    } else {
+        args.days
+            .iter()
+            .map(|&day| find(year, day))
+            .collect::<Result<_, _>>()?
+    };
+
+    let options = if args.quick {
+        Options {
+            warmup: Duration::from_millis(20),
+            measure: Duration::from_millis(100),
+            ..Options::default()
+        }
+    } else {
+        Options::default()
+    };
+
+    let mut report = BenchReport::default();
+    for solver in solvers {
+        let input = InputSource::Default
+            .read(&config, year, solver.day())
+            .map_err(|e| e.to_string())?;
+        let day = bench::bench_day(solver, &input, &options).map_err(|e| e.to_string())?;
+        print!("{}", day);
+        report.days.push(day);
+    }
+
+    let baseline = match fs::read_to_string(&baseline_path) {
+        Ok(json) => Some(
+            serde_json::from_str::<BenchReport>(&json)
+                .map_err(|e| format!("{}: {}", baseline_path.display(), e))?,
+        ),
+        Err(_) => None,
+    };
+
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    let markdown = report.to_markdown(baseline.as_ref(), args.threshold);
+    let write = |path: PathBuf, contents: &str| {
+        fs::write(&path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+    };
+    fs::create_dir_all(&out).map_err(|e| format!("{}: {}", out.display(), e))?;
+    write(out.join("report.json"), &json)?;
+    write(out.join("report.md"), &markdown)?;
+    println!("Wrote {}", out.join("report.md").display());
+
+    if args.save_baseline {
+        write(baseline_path.clone(), &json)?;
+        println!("Saved baseline {}", baseline_path.display());
+    }
+
+    let Some(baseline) = baseline else {
+        return Ok(());
+    };
+    let regressions = report.regressions(&baseline, args.threshold);
+    if regressions.is_empty() {
+        println!("No regressions beyond {}%", args.threshold);
+        return Ok(());
+    }
+    for regression in &regressions {
+        println!("Regression: {}", regression);
+    }
+    Err(format!(
+        "{} timings regressed by more than {}%",
+        regressions.len(),
+        args.threshold
+    ))
+}
+
+fn gen(args: GenArgs) -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let year = args.year.unwrap_or(config.year);
+    let input = generate::generate(year, args.day, args.size, args.seed)
+        .ok_or_else(|| format!("no generator for day {} of {}", args.day, year))?;
+
+    match args.out {
+        Some(path) => fs::write(&path, input).map_err(|e| format!("{}: {}", path.display(), e)),
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn params(args: ParamsArgs) -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let year = args.year.unwrap_or(config.year);
+    let solver = find(year, args.day)?;
+    let params = overrides(&config, year, args.day, &args.params)?;
+
+    let params = solver.params(&params).map_err(|e| e.to_string())?;
+    match params.is_empty() {
+        true => println!("Day {:02} has no parameters", args.day),
+        false => print!("{}", params),
+    }
+    Ok(())
+}
+
+fn puzzle(args: PuzzleArgs) -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let year = args.year.unwrap_or(config.year);
+
+    let path = match args.from {
+        Some(from) => {
+            let html =
+                fs::read_to_string(&from).map_err(|e| format!("{}: {}", from.display(), e))?;
+            fetch::save_puzzle(&config, year, args.day, &html).map_err(|e| e.to_string())?
+        }
+        None => {
+            let client = Client::new(&config);
+            fetch::fetch_puzzle(&client, &config, year, args.day, args.force)
+                .map_err(|e| e.to_string())?
+                .path()
+                .to_path_buf()
+        }
+    };
+
+    let html = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    print!("{}", puzzle::render(&html));
+    Ok(())
+}
+
+fn examples(args: ExamplesArgs) -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let year = args.year.unwrap_or(config.year);
+
+    let path = config.puzzle_path(year, args.day);
+    let html = fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "{}: {}, so run `aoc puzzle {}` first",
+            path.display(),
+            e,
+            args.day
+        )
+    })?;
+    let found = puzzle::extract(&html);
+    if found.is_empty() {
+        return Err(format!("no examples in {}", path.display()));
+    }
+
+    let out = config.examples_path(year, args.day);
+    if out.exists() && !args.force {
+        return Err(format!(
+            "{} already exists, use --force to replace it",
+            out.display()
+        ));
+    }
+    let source = path.strip_prefix(&config.root).unwrap_or(&path);
+    let text = examples::to_toml(&source.display().to_string(), &found);
+    if let Some(dir) = out.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    fs::write(&out, text).map_err(|e| format!("{}: {}", out.display(), e))?;
+    match found.len() {
+        1 => println!("Wrote 1 example to {}", out.display()),
+        n => println!("Wrote {} examples to {}", n, out.display()),
+    }
+
+    if let Some(solver) = aoc_2021::find(year, args.day) {
+        for failure in examples::check(solver, &found) {
+            println!("  {}", failure);
+        }
+    }
+    Ok(())
+}
+
+fn leaderboard(args: LeaderboardArgs) -> Result<(), String> {
+    let leaderboard = Leaderboard::load(&args.file)?;
+
+    match args.day {
+        Some(day) if !leaderboard.days().contains(&day) => {
+            return Err(format!("nobody has a star for day {} yet", day));
+        }
+        Some(day) => {
+            println!("Day {:02} of {}", day, leaderboard.year);
+            print!("{}", leaderboard.day_table(day));
+        }
+        None => {
+            println!("{} ranked by {}", leaderboard.year, args.scoring);
+            print!("{}", leaderboard.ranking_table(args.scoring));
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new(args),
+        Command::Bench(args) => bench(args),
+        Command::Gen(args) => gen(args),
+        Command::Params(args) => params(args),
+        Command::Puzzle(args) => puzzle(args),
+        Command::Examples(args) => examples(args),
+        Command::Leaderboard(args) => leaderboard(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,402 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::answers::KnownAnswers;
+use crate::config::{Config, DEFAULT_PROFILE};
+use crate::error::ReadError;
+use crate::examples;
+use crate::generate;
+use crate::params::Overrides;
+use crate::runner::{self, InputSource};
+use crate::solution::{Part, Solver};
+
+/// What one of the checks below found: answers that are wrong or files that
+/// disagree with the registry, and what there was nothing to check against
+/// yet, like a day just created by `aoc new`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Outcome {
+    pub failures: Vec<String>,
+    pub skipped: Vec<String>,
+}
+
+impl Outcome {
+    /// Logs what was skipped and panics if anything failed, for the tests
+    /// that run the checks.
+    pub fn assert_passed(&self) {
+        for skipped in &self.skipped {
+            eprintln!("skipped {}", skipped);
+        }
+        assert!(self.failures.is_empty(), "\n{}", self.failures.join("\n"));
+    }
+}
+
+fn is_registered(solvers: &[&dyn Solver], year: u16, day: u8) -> bool {
+    solvers
+        .iter()
+        .any(|solver| (solver.year(), solver.day()) == (year, day))
+}
+
+/// Every `dayNN.rs` under `src/` of `root` is declared and listed in its
+/// year's `mod.rs`, and every year is in `lib.rs`, as `aoc new` leaves them.
+pub fn registry(root: &Path) -> Outcome {
+    let mut outcome = Outcome::default();
+    let src = root.join("src");
+    let read =
+        |path: &Path| fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e));
+
+    let lib = match read(&src.join("lib.rs")) {
+        Ok(lib) => lib,
+        Err(e) => {
+            outcome.failures.push(e);
+            return outcome;
+        }
+    };
+    let years = match fs::read_dir(&src) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter_map(|name| name.strip_prefix('y')?.parse::<u16>().ok())
+            .collect::<Vec<_>>(),
+        Err(e) => {
+            outcome.failures.push(format!("{}: {}", src.display(), e));
+            return outcome;
+        }
+    };
+
+    for year in years {
+        let module = format!("y{}", year);
+        for entry in [
+            format!("pub mod {};", module),
+            format!("({}, {}::DAYS)", year, module),
+        ] {
+            if !lib.contains(&entry) {
+                outcome
+                    .failures
+                    .push(format!("src/lib.rs: missing `{}`", entry));
+            }
+        }
+
+        let dir = src.join(&module);
+        let text = match read(&dir.join("mod.rs")) {
+            Ok(text) => text,
+            Err(e) => {
+                outcome.failures.push(e);
+                continue;
+            }
+        };
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut days = entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter_map(|name| {
+                name.strip_prefix("day")?
+                    .strip_suffix(".rs")?
+                    .parse::<u8>()
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+        days.sort();
+        for day in days {
+            let entries = [
+                format!("pub mod day{:02};", day),
+                format!("day{:02}::Day{:02}", day, day),
+            ];
+            for entry in entries {
+                if !text.contains(&entry) {
+                    outcome
+                        .failures
+                        .push(format!("src/{}/mod.rs: missing `{}`", module, entry));
+                }
+            }
+        }
+    }
+    outcome
+}
+
+/// Solves each of `solvers` on every input profile and compares the answers
+/// with those in `known`. Days without recorded answers or without inputs are
+/// skipped; answers for days that aren't registered are failures.
+pub fn known_answers(config: &Config, known: &KnownAnswers, solvers: &[&dyn Solver]) -> Outcome {
+    let mut outcome = Outcome::default();
+
+    for solver in solvers {
+        let (year, day) = (solver.year(), solver.day());
+        if known.day(year, day).is_none() {
+            outcome
+                .skipped
+                .push(format!("{} day {:02}: no entry in answers.toml", year, day));
+            continue;
+        }
+
+        let profiles = match runner::profiles(config, year, day) {
+            Ok(profiles) => profiles,
+            Err(e) => {
+                outcome
+                    .failures
+                    .push(format!("{} day {:02}: {}", year, day, e));
+                continue;
+            }
+        };
+        for profile in profiles {
+            let Some(answers) = known.profile(year, day, &profile) else {
+                outcome.skipped.push(format!(
+                    "{} day {:02}: no answers for the {} profile",
+                    year, day, profile
+                ));
+                continue;
+            };
+
+            let path = config.profile_path(year, day, &profile);
+            let input = match fs::read_to_string(&path) {
+                Ok(input) => input,
+                Err(e) => {
+                    outcome.failures.push(format!("{}: {}", path.display(), e));
+                    continue;
+                }
+            };
+            let parsed = match solver.parse(&input) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    outcome.failures.push(format!("{} profile: {}", profile, e));
+                    continue;
+                }
+            };
+
+            for part in Part::ALL {
+                let Some(expected) = answers.get(part) else {
+                    continue;
+                };
+                match parsed.solve(part) {
+                    Ok(answer) if expected.matches(&answer) => {}
+                    Ok(answer) => outcome.failures.push(format!(
+                        "{} day {:02} part {} ({} profile): expected {}, got {}",
+                        year, day, part, profile, expected, answer
+                    )),
+                    Err(e) => outcome.failures.push(format!("{} profile: {}", profile, e)),
+                }
+            }
+        }
+    }
+
+    for (year, day) in known.days() {
+        if !is_registered(solvers, year, day) {
+            outcome.failures.push(format!(
+                "{} day {:02}: answers.toml entry for an unregistered day",
+                year, day
+            ));
+            continue;
+        }
+        let Ok(profiles) = runner::profiles(config, year, day) else {
+            continue;
+        };
+        for profile in known.day(year, day).unwrap().profiles.keys() {
+            if !profiles.contains(profile) {
+                outcome.skipped.push(format!(
+                    "{} day {:02}: no input for the {} profile in answers.toml",
+                    year, day, profile
+                ));
+            }
+        }
+        if !profiles.iter().any(|profile| profile == DEFAULT_PROFILE) {
+            outcome.skipped.push(format!(
+                "{} day {:02}: no input to check answers.toml against",
+                year, day
+            ));
+        }
+    }
+    outcome
+}
+
+/// Streams each day's default input through its solver and compares the
+/// answers with those in `known`, skipping days without either.
+pub fn streamed_answers(config: &Config, known: &KnownAnswers, solvers: &[&dyn Solver]) -> Outcome {
+    let mut outcome = Outcome::default();
+
+    for &solver in solvers {
+        let (year, day) = (solver.year(), solver.day());
+        let Some(answers) = known.day(year, day) else {
+            outcome
+                .skipped
+                .push(format!("{} day {:02}: no entry in answers.toml", year, day));
+            continue;
+        };
+
+        let params = Overrides::new();
+        let report =
+            match runner::run_stream(solver, config, &InputSource::Default, &Part::ALL, &params) {
+                Ok(report) => report,
+                Err(ReadError::Io(e)) if e.kind() == io::ErrorKind::NotFound => {
+                    outcome
+                        .skipped
+                        .push(format!("{} day {:02}: no input", year, day));
+                    continue;
+                }
+                Err(e) => {
+                    outcome.failures.push(e.to_string());
+                    continue;
+                }
+            };
+        for part in report.parts {
+            match answers.get(part.part) {
+                Some(expected) if !expected.matches(&part.answer) => {
+                    outcome.failures.push(format!(
+                        "{} day {:02} part {}: expected {}, got {} when streamed",
+                        year, day, part.part, expected, part.answer
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+    outcome
+}
+
+/// Solves the examples in each day's fixture, skipping days without one.
+/// Fixtures for days that aren't registered are failures.
+pub fn examples(config: &Config, solvers: &[&dyn Solver]) -> Outcome {
+    let mut outcome = Outcome::default();
+
+    for &solver in solvers {
+        let (year, day) = (solver.year(), solver.day());
+        let path = config.examples_path(year, day);
+        if !path.exists() {
+            outcome
+                .skipped
+                .push(format!("{} day {:02}: no examples in fixtures/", year, day));
+            continue;
+        }
+
+        match examples::load(&path) {
+            Ok(examples) => {
+                for failure in examples::check(solver, &examples) {
+                    outcome
+                        .failures
+                        .push(format!("{} day {:02} {}", year, day, failure));
+                }
+            }
+            Err(e) => outcome.failures.push(e),
+        }
+    }
+
+    let Ok(years) = fs::read_dir(config.root.join("fixtures")) else {
+        return outcome;
+    };
+    for year in years.filter_map(Result::ok) {
+        let year = year.path();
+        let Ok(files) = fs::read_dir(&year) else {
+            continue;
+        };
+        for file in files.filter_map(Result::ok) {
+            let file = file.path();
+            let registered = year
+                .file_name()
+                .and_then(|year| year.to_str()?.parse().ok())
+                .zip(file.file_stem().and_then(|day| day.to_str()?.parse().ok()))
+                .is_some_and(|(year, day)| is_registered(solvers, year, day));
+            if !registered {
+                outcome
+                    .failures
+                    .push(format!("{}: not a registered day", file.display()));
+            }
+        }
+    }
+    outcome
+}
+
+/// Generates a few inputs for each day and solves them, skipping days that
+/// have no generator yet.
+pub fn generators(solvers: &[&dyn Solver]) -> Outcome {
+    let mut outcome = Outcome::default();
+
+    for &solver in solvers {
+        let (year, day) = (solver.year(), solver.day());
+        if generate::generator(year, day).is_none() {
+            outcome
+                .skipped
+                .push(format!("{} day {:02}: no input generator", year, day));
+            continue;
+        }
+
+        for seed in 0..3 {
+            let input = generate::generate(year, day, 30, seed).expect("checked above");
+            let parsed = match solver.parse(&input) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    outcome
+                        .failures
+                        .push(format!("seed {}: {}\n{}", seed, e, input));
+                    continue;
+                }
+            };
+            for part in Part::ALL {
+                if let Err(e) = parsed.solve(part) {
+                    outcome
+                        .failures
+                        .push(format!("seed {}, part {}: {}\n{}", seed, part, e, input));
+                }
+            }
+        }
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_registry() {
+        let root = crate::config::default_root();
+
+        assert_eq!(registry(&root), Outcome::default());
+    }
+
+    #[test]
+    fn test_registry_missing_entries() {
+        let dir = TempDir::new("checks-registry");
+        dir.write("src/lib.rs", "pub mod y2022;\n");
+        dir.write("src/y2022/mod.rs", "pub mod day01;\n");
+        dir.write("src/y2022/day01.rs", "");
+
+        assert_eq!(
+            registry(dir.path()).failures,
+            vec![
+                "src/lib.rs: missing `(2022, y2022::DAYS)`",
+                "src/y2022/mod.rs: missing `day01::Day01`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skipped() {
+        let dir = TempDir::new("checks-skipped");
+        let config = Config {
+            root: dir.path().to_path_buf(),
+            ..Config::default()
+        };
+        let known = KnownAnswers::parse("[2022.01]\npart1 = 24000\n").unwrap();
+        let solvers = [crate::find(2022, 1).unwrap(), crate::find(2022, 4).unwrap()];
+
+        let outcome = known_answers(&config, &known, &solvers);
+        assert!(outcome.failures.is_empty());
+        assert_eq!(
+            outcome.skipped,
+            vec![
+                "2022 day 04: no entry in answers.toml",
+                "2022 day 01: no input to check answers.toml against",
+            ]
+        );
+        let outcome = streamed_answers(&config, &known, &solvers);
+        assert!(outcome.failures.is_empty());
+        assert_eq!(outcome.skipped.len(), 2);
+
+        dir.write("input/2022/01.txt", "1000\n");
+        let outcome = known_answers(&config, &known, &solvers);
+        assert_eq!(
+            outcome.failures,
+            vec!["2022 day 01 part 1 (default profile): expected 24000, got 1000"]
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::params::Overrides;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2022;
+/// The name of the profile read from [`Config::input_path`]. Other profiles
+/// are teammates' inputs, kept next to it in a directory named after the day.
+pub const DEFAULT_PROFILE: &str = "default";
+
+pub const DEFAULT_USER_AGENT: &str = "github.com/dankirkham/aoc-2021 by daniel.a.kirkham@gmail.com";
+
+/// The crate root, which holds `input/` and `aoc.toml`. Resolved at compile
+/// time so the tools work from any directory, and overridable with `AOC_ROOT`.
+pub fn default_root() -> PathBuf {
+    match env::var_os("AOC_ROOT") {
+        Some(root) => PathBuf::from(root),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")),
+    }
+}
+
+/// Settings for talking to the Advent of Code site, read from `aoc.toml` and
+/// the environment. The session cookie is never read from `aoc.toml` itself so
+/// that the file can be committed; it comes from `AOC_SESSION` or from
+/// `session_file` (`.cookie` by default).
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub base_url: String,
+    pub user_agent: String,
+    pub session_file: PathBuf,
+    /// The event the solutions belong to.
+    pub year: u16,
+    /// Where submitted answers are recorded.
+    pub history_file: PathBuf,
+    /// Overrides for the days' parameters, from `[params.YYYY.NN]` tables.
+    pub params: BTreeMap<String, BTreeMap<String, Overrides>>,
+
+    #[serde(skip)]
+    pub root: PathBuf,
+    #[serde(skip)]
+    pub session: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            session_file: PathBuf::from(".cookie"),
+            year: DEFAULT_YEAR,
+            history_file: PathBuf::from(".submissions.json"),
+            params: BTreeMap::new(),
+            root: default_root(),
+            session: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ConfigError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    /// Loads the configuration for the default root, honouring `AOC_CONFIG`,
+    /// `AOC_BASE_URL` and `AOC_SESSION`.
+    pub fn load() -> Result<Self, ConfigError> {
+        let root = default_root();
+        let path = match env::var_os("AOC_CONFIG") {
+            Some(path) => PathBuf::from(path),
+            None => root.join("aoc.toml"),
+        };
+
+        let mut config = Self::from_file(&path, root)?;
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session.trim().to_string());
+        }
+        Ok(config)
+    }
+
+    /// Reads `path` if it exists, falling back to the defaults otherwise.
+    pub fn from_file(path: &Path, root: PathBuf) -> Result<Self, ConfigError> {
+        let error = |message: String| ConfigError {
+            path: path.to_path_buf(),
+            message,
+        };
+
+        let mut config: Config = match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| error(e.to_string()))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(error(e.to_string())),
+        };
+        for (year, days) in &config.params {
+            if parse_year(year).is_none() {
+                return Err(error(format!("params.{} is not a year", year)));
+            }
+            if let Some(day) = days.keys().find(|day| parse_day(day).is_none()) {
+                return Err(error(format!("params.{}.{} is not a day", year, day)));
+            }
+        }
+        config.root = root;
+        config.base_url = config.base_url.trim_end_matches('/').to_string();
+
+        let session_file = config.root.join(&config.session_file);
+        config.session = match fs::read_to_string(&session_file) {
+            Ok(session) => Some(session.trim().to_string()).filter(|s| !s.is_empty()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => {
+                return Err(ConfigError {
+                    path: session_file,
+                    message: e.to_string(),
+                })
+            }
+        };
+
+        Ok(config)
+    }
+
+    /// The parameter overrides for `day` of `year`, which are empty if there
+    /// are none.
+    pub fn params(&self, year: u16, day: u8) -> Overrides {
+        self.params
+            .iter()
+            .filter(|(key, _)| parse_year(key) == Some(year))
+            .flat_map(|(_, days)| days)
+            .find(|(key, _)| parse_day(key) == Some(day))
+            .map(|(_, params)| params.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn history_path(&self) -> PathBuf {
+        self.root.join(&self.history_file)
+    }
+
+    pub fn input_dir(&self) -> PathBuf {
+        self.root.join("input")
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.input_dir()
+            .join(year.to_string())
+            .join(format!("{:02}.txt", day))
+    }
+
+    pub fn profile_path(&self, year: u16, day: u8, profile: &str) -> PathBuf {
+        if profile == DEFAULT_PROFILE {
+            return self.input_path(year, day);
+        }
+        self.input_dir()
+            .join(year.to_string())
+            .join(format!("{:02}", day))
+            .join(format!("{}.txt", profile))
+    }
+
+    /// Where the puzzle page for `day` of `year` is cached.
+    pub fn puzzle_path(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join("puzzles")
+            .join(year.to_string())
+            .join(format!("{:02}.html", day))
+    }
+
+    /// The example fixtures for `day` of `year`. They're kept out of
+    /// `examples/`, which Cargo reserves for example programs.
+    pub fn examples_path(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join("fixtures")
+            .join(year.to_string())
+            .join(format!("{:02}.toml", day))
+    }
+}
+
+/// Reads an event year from a table key such as `2022`.
+fn parse_year(key: &str) -> Option<u16> {
+    key.parse().ok().filter(|year| *year >= 2015)
+}
+
+/// Reads a day from a table key such as `07`.
+fn parse_day(key: &str) -> Option<u8> {
+    key.parse().ok().filter(|day| (1..=25).contains(day))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_defaults_without_file() {
+        let dir = TempDir::new("config-defaults");
+        let config = Config::from_file(&dir.path().join("aoc.toml"), dir.path().into()).unwrap();
+
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(config.session, None);
+        assert_eq!(
+            config.input_path(2022, 7),
+            dir.path().join("input/2022/07.txt")
+        );
+        assert_eq!(
+            config.profile_path(2022, 7, "bob"),
+            dir.path().join("input/2022/07/bob.txt")
+        );
+        assert_eq!(
+            config.profile_path(2022, 7, DEFAULT_PROFILE),
+            config.input_path(2022, 7)
+        );
+        assert_eq!(
+            config.puzzle_path(2022, 7),
+            dir.path().join("puzzles/2022/07.html")
+        );
+        assert_eq!(
+            config.examples_path(2022, 7),
+            dir.path().join("fixtures/2022/07.toml")
+        );
+    }
+
+    #[test]
+    fn test_from_file() {
+        let dir = TempDir::new("config-file");
+        dir.write(
+            "aoc.toml",
+            "base_url = \"http://localhost:1234/\"\nsession_file = \"secret\"\n",
+        );
+        dir.write("secret", "abc123\n");
+
+        let config = Config::from_file(&dir.path().join("aoc.toml"), dir.path().into()).unwrap();
+
+        assert_eq!(config.base_url, "http://localhost:1234");
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+    }
+
+    #[test]
+    fn test_params() {
+        let dir = TempDir::new("config-params");
+        dir.write("aoc.toml", "[params.2022.15]\nrow = 10\n");
+        let config = Config::from_file(&dir.path().join("aoc.toml"), dir.path().into()).unwrap();
+
+        let mut expected = Overrides::new();
+        expected.set_arg("row=10").unwrap();
+        assert_eq!(config.params(2022, 15), expected);
+        assert!(config.params(2022, 6).is_empty());
+        assert!(config.params(2021, 15).is_empty());
+
+        dir.write("aoc.toml", "[params.2022.day15]\nrow = 10\n");
+        let error = Config::from_file(&dir.path().join("aoc.toml"), dir.path().into()).unwrap_err();
+        assert_eq!(error.message, "params.2022.day15 is not a day");
+
+        dir.write("aoc.toml", "[params.22.15]\nrow = 10\n");
+        let error = Config::from_file(&dir.path().join("aoc.toml"), dir.path().into()).unwrap_err();
+        assert_eq!(error.message, "params.22 is not a year");
+    }
+
+    #[test]
+    fn test_rejects_unknown_keys() {
+        let dir = TempDir::new("config-unknown");
+        dir.write("aoc.toml", "session = \"abc123\"\n");
+
+        let error = Config::from_file(&dir.path().join("aoc.toml"), dir.path().into()).unwrap_err();
+
+        assert!(error.message.contains("unknown field `session`"));
+    }
+}
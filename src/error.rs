@@ -0,0 +1,354 @@
+use std::any;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use crate::solution::Part;
+
+/// An error in a day's puzzle input. Lines and columns are 1-based; a line of
+/// 0 means the error concerns the input as a whole.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error at `span`, which must be a slice of `input`.
+    pub fn new(day: u8, input: &str, span: &str, message: impl fmt::Display) -> Self {
+        let (line, column) = position(input, span);
+        Self {
+            day,
+            line,
+            column,
+            text: span.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// An error about the whole of line `line`, found after parsing.
+    pub fn at_line(
+        day: u8,
+        line: usize,
+        text: impl Into<String>,
+        message: impl fmt::Display,
+    ) -> Self {
+        Self {
+            day,
+            line,
+            column: 1,
+            text: text.into(),
+            message: message.to_string(),
+        }
+    }
+
+    /// An error that can't be attributed to a single position in the input.
+    pub fn input(day: u8, message: impl fmt::Display) -> Self {
+        Self {
+            day,
+            line: 0,
+            column: 0,
+            text: String::new(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Moves an error raised while parsing `fragment` to its position within
+    /// `input`, of which `fragment` must be a slice.
+    pub fn rebase(mut self, input: &str, fragment: &str) -> Self {
+        if self.line == 0 {
+            return self;
+        }
+
+        let (line, column) = position(input, fragment);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    /// Moves an error raised while parsing a single line, such as one read
+    /// from a stream, to line `line` of the input.
+    pub fn on_line(mut self, line: usize) -> Self {
+        if self.line > 0 {
+            self.line += line - 1;
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {:02}", self.day)?;
+        if self.line > 0 {
+            write!(f, ", line {}, column {}", self.line, self.column)?;
+        }
+        write!(f, ": {}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " ({:?})", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// An error solving a part from input that parsed, such as an answer that
+/// doesn't fit in its type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveError {
+    pub day: u8,
+    pub part: Part,
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(day: u8, part: Part, message: impl fmt::Display) -> Self {
+        Self {
+            day,
+            part,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:02}, part {}: {}",
+            self.day, self.part, self.message
+        )
+    }
+}
+
+impl Error for SolveError {}
+
+/// An error solving a day in memory: either the input didn't parse, or a part
+/// couldn't be solved from it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DayError {
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "{}", e),
+            Self::Solve(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for DayError {}
+
+impl From<ParseError> for DayError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<SolveError> for DayError {
+    fn from(e: SolveError) -> Self {
+        Self::Solve(e)
+    }
+}
+
+/// An error reading a day's input from a stream.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Parse(e) => write!(f, "{}", e),
+            Self::Solve(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<SolveError> for ReadError {
+    fn from(e: SolveError) -> Self {
+        Self::Solve(e)
+    }
+}
+
+impl From<DayError> for ReadError {
+    fn from(e: DayError) -> Self {
+        match e {
+            DayError::Parse(e) => Self::Parse(e),
+            DayError::Solve(e) => Self::Solve(e),
+        }
+    }
+}
+
+/// Returns the 1-based line and column at which `span` starts within `input`.
+/// Spans that don't point into `input` are treated as being at its end.
+fn position(input: &str, span: &str) -> (usize, usize) {
+    let offset = (span.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset <= input.len())
+        .unwrap_or(input.len());
+    let before = &input[..offset];
+
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+
+    (line, column)
+}
+
+/// The empty slice at the end of `s`, used to report missing text.
+pub fn end_of(s: &str) -> &str {
+    &s[s.len()..]
+}
+
+/// A day's input, used to build [`ParseError`]s pointing into it.
+#[derive(Copy, Clone, Debug)]
+pub struct Source<'a> {
+    day: u8,
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Self { day, input }
+    }
+
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    pub fn error(&self, span: &str, message: impl fmt::Display) -> ParseError {
+        ParseError::new(self.day, self.input, span, message)
+    }
+
+    pub fn parse<T>(&self, span: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        span.parse::<T>()
+            .map_err(|e| self.error(span, format!("expected {}: {}", any::type_name::<T>(), e)))
+    }
+
+    pub fn strip_prefix<'s>(&self, s: &'s str, prefix: &str) -> Result<&'s str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("expected {:?}", prefix)))
+    }
+
+    pub fn split_once<'s>(
+        &self,
+        s: &'s str,
+        delimiter: &str,
+    ) -> Result<(&'s str, &'s str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(end_of(s), format!("expected {:?}", delimiter)))
+    }
+
+    /// Takes the next token, reporting `what` was missing at the end of `line`.
+    pub fn next<'s>(
+        &self,
+        tokens: &mut impl Iterator<Item = &'s str>,
+        line: &str,
+        what: &str,
+    ) -> Result<&'s str, ParseError> {
+        tokens
+            .next()
+            .ok_or_else(|| self.error(end_of(line), format!("expected {}", what)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "move 1 from 2 to 1\nmove x from 1 to 3\n";
+
+    #[test]
+    fn test_position() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let error = ParseError::new(5, INPUT, &line[5..6], "bad");
+
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.text, "x");
+        assert_eq!(error.to_string(), "day 05, line 2, column 6: bad (\"x\")");
+    }
+
+    #[test]
+    fn test_position_at_end() {
+        let error = ParseError::new(5, INPUT, end_of(INPUT), "truncated");
+
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.to_string(), "day 05, line 3, column 1: truncated");
+    }
+
+    #[test]
+    fn test_input_error() {
+        let error = ParseError::input(11, "no monkeys");
+
+        assert_eq!(error.to_string(), "day 11: no monkeys");
+    }
+
+    #[test]
+    fn test_solve_error() {
+        let error = DayError::from(SolveError::new(11, Part::Two, "worry level overflows"));
+
+        assert_eq!(error.to_string(), "day 11, part 2: worry level overflows");
+    }
+
+    #[test]
+    fn test_rebase() {
+        let fragment = &INPUT[24..];
+        let error = Source::new(5, fragment)
+            .parse::<u32>(&fragment[..1])
+            .unwrap_err()
+            .rebase(INPUT, fragment);
+        assert_eq!((error.line, error.column), (2, 6));
+
+        let error = ParseError::new(5, fragment, &fragment[2..], "bad").rebase(INPUT, fragment);
+        assert_eq!((error.line, error.column), (2, 8));
+    }
+
+    #[test]
+    fn test_source_helpers() {
+        let source = Source::new(4, "2-4,6-8");
+        let input = source.input();
+
+        assert_eq!(source.parse::<u32>(&input[..1]), Ok(2));
+        assert_eq!(source.split_once(input, ","), Ok(("2-4", "6-8")));
+
+        let error = source.split_once(input, ";").unwrap_err();
+        assert_eq!(error.column, 8);
+        assert_eq!(error.message, "expected \";\"");
+
+        let error = source.parse::<u32>(&input[1..3]).unwrap_err();
+        assert_eq!(error.column, 2);
+        assert_eq!(error.message, "expected u32: invalid digit found in string");
+    }
+}
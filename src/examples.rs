@@ -0,0 +1,161 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::answers::Expected;
+use crate::params::Overrides;
+use crate::solution::{Part, Solver};
+
+/// A puzzle's example input and the answers it should give, as kept in
+/// `fixtures/<year>/NN.toml`. Examples that use smaller sizes than the real
+/// puzzle set them in `params`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+    #[serde(default)]
+    pub params: Overrides,
+}
+
+impl Example {
+    pub fn get(&self, part: Part) -> Option<&Expected> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixture {
+    #[serde(default)]
+    examples: Vec<Example>,
+}
+
+pub fn parse(text: &str) -> Result<Vec<Example>, String> {
+    let fixture: Fixture = toml::from_str(text).map_err(|e| e.to_string())?;
+    Ok(fixture.examples)
+}
+
+pub fn load(path: &Path) -> Result<Vec<Example>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Writes `examples` as a fixture file. Inputs are kept verbatim in literal
+/// strings, so the file reads like the puzzle; the params of each example are
+/// left for the reader to fill in.
+pub fn to_toml(source: &str, examples: &[Example]) -> String {
+    let mut text = format!(
+        "# Examples from {}, checked by tests/examples.rs.\n\
+         # The answers are guessed from the text, so check them, and add a\n\
+         # [examples.params] table where an example uses smaller sizes.\n",
+        source
+    );
+
+    for example in examples {
+        text.push_str("\n[[examples]]\n");
+        if example.input.contains("'''") {
+            let input = toml::Value::String(example.input.clone());
+            text.push_str(&format!("input = {}\n", input));
+        } else {
+            text.push_str(&format!("input = '''\n{}'''\n", example.input));
+        }
+        for part in Part::ALL {
+            let value = match example.get(part) {
+                Some(Expected::Integer(n)) => n.to_string(),
+                Some(Expected::Text(answer)) => toml::Value::String(answer.clone()).to_string(),
+                None => continue,
+            };
+            text.push_str(&format!("part{} = {}\n", part, value));
+        }
+    }
+    text
+}
+
+/// Solves each example with `solver` and describes every answer that differs
+/// from the one expected. Parts the solver leaves unsolved, as a day just
+/// created by `aoc new` does, aren't compared.
+pub fn check(solver: &dyn Solver, examples: &[Example]) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    for (i, example) in examples.iter().enumerate() {
+        let parsed = match solver.parse_with(&example.input, &example.params) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                failures.push(format!("example {}: {}", i + 1, e));
+                continue;
+            }
+        };
+        for part in Part::ALL {
+            let Some(expected) = example.get(part) else {
+                continue;
+            };
+            match parsed.solve(part) {
+                Ok(answer) if !answer.is_solved() || expected.matches(&answer) => {}
+                Ok(answer) => failures.push(format!(
+                    "example {} part {}: expected {}, got {}",
+                    i + 1,
+                    part,
+                    expected,
+                    answer
+                )),
+                Err(e) => failures.push(format!("example {}: {}", i + 1, e)),
+            }
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let examples = vec![
+            Example {
+                input: "1000\n2000\n\n4000\n".to_string(),
+                part1: Some(Expected::Integer(4000)),
+                part2: Some(Expected::Text("AB\"C".to_string())),
+                ..Example::default()
+            },
+            Example {
+                input: "'''\n".to_string(),
+                ..Example::default()
+            },
+        ];
+        let text = to_toml("puzzles/2022/01.html", &examples);
+
+        assert!(text.contains("input = '''\n1000\n2000\n\n4000\n'''\npart1 = 4000\n"));
+        assert_eq!(parse(&text).unwrap(), examples);
+    }
+
+    #[test]
+    fn test_check() {
+        let solver = crate::find(2022, 15).unwrap();
+        let mut examples = parse(
+            r#"
+[[examples]]
+input = """
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+"""
+part1 = 12
+params = { row = 10 }
+"#,
+        )
+        .unwrap();
+
+        assert!(check(solver, &examples).is_empty());
+
+        examples[0].params = Overrides::new();
+        assert_eq!(
+            check(solver, &examples),
+            vec!["example 1 part 1: expected 12, got 0"]
+        );
+    }
+}
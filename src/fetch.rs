@@ -0,0 +1,248 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::http::{Client, HttpError};
+
+#[derive(Debug)]
+pub enum FetchError {
+    Http(HttpError),
+    Status(u16, String),
+    /// The site answered, but not with the input or page that was asked for.
+    Rejected(String),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Http(e) => write!(f, "{}", e),
+            Self::Status(status, body) => write!(f, "server returned {}: {}", status, body),
+            Self::Rejected(reason) => write!(f, "rejected: {}", reason),
+            Self::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<HttpError> for FetchError {
+    fn from(e: HttpError) -> Self {
+        Self::Http(e)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Cached(path) | Self::Downloaded(path) => path,
+        }
+    }
+}
+
+/// Downloads the input for `day` of `year` into the input directory, unless a
+/// non-empty copy is already there and `force` is not set. The existing file
+/// is only replaced once a valid input has been received.
+pub fn fetch_input(
+    client: &Client,
+    config: &Config,
+    year: u16,
+    day: u8,
+    force: bool,
+) -> Result<Fetched, FetchError> {
+    let path = config.input_path(year, day);
+    if !force && is_cached(&path) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let response = client.get(&format!("/{}/day/{}/input", year, day))?;
+    if response.status != 200 {
+        return Err(FetchError::Status(response.status, summary(&response.body)));
+    }
+    validate(&response.body)?;
+
+    write_atomic(&path, &response.body).map_err(|e| FetchError::Io(path.clone(), e))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Downloads the puzzle page for `day` of `year` into the puzzle cache,
+/// unless it's already there and `force` is not set. Part two only appears on
+/// the page once part one is solved, so it's worth fetching again then.
+pub fn fetch_puzzle(
+    client: &Client,
+    config: &Config,
+    year: u16,
+    day: u8,
+    force: bool,
+) -> Result<Fetched, FetchError> {
+    let path = config.puzzle_path(year, day);
+    if !force && is_cached(&path) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let response = client.get(&format!("/{}/day/{}", year, day))?;
+    if response.status != 200 {
+        return Err(FetchError::Status(response.status, summary(&response.body)));
+    }
+    save_puzzle(config, year, day, &response.body).map(Fetched::Downloaded)
+}
+
+/// Stores a puzzle page, such as one saved from a browser, in the puzzle
+/// cache.
+pub fn save_puzzle(config: &Config, year: u16, day: u8, html: &str) -> Result<PathBuf, FetchError> {
+    if !html.contains("<article") {
+        return Err(FetchError::Rejected(
+            "no puzzle description in the page".to_string(),
+        ));
+    }
+
+    let path = config.puzzle_path(year, day);
+    write_atomic(&path, html).map_err(|e| FetchError::Io(path.clone(), e))?;
+    Ok(path)
+}
+
+fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).map(|m| m.len() > 0).unwrap_or(false)
+}
+
+/// Rejects the pages the site serves in place of an input, which come back
+/// with a 200 status in some cases.
+fn validate(body: &str) -> Result<(), FetchError> {
+    let reject = |reason: &str| Err(FetchError::Rejected(reason.to_string()));
+
+    if body.trim().is_empty() {
+        return reject("empty response");
+    }
+    if body.starts_with("Puzzle inputs differ by user") {
+        return reject("not logged in, check the session cookie");
+    }
+    if body.starts_with("Please don't repeatedly request this endpoint before it unlocks") {
+        return reject("the puzzle has not unlocked yet");
+    }
+    let start = body.trim_start().to_ascii_lowercase();
+    if start.starts_with("<!doctype") || start.starts_with("<html") {
+        return reject("received an HTML page");
+    }
+    Ok(())
+}
+
+fn summary(body: &str) -> String {
+    body.lines().next().unwrap_or("").trim().to_string()
+}
+
+fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{FakeServer, TempDir};
+
+    fn setup(dir: &TempDir, server: &FakeServer) -> (Client, Config) {
+        let config = Config {
+            base_url: server.url.clone(),
+            root: dir.path().to_path_buf(),
+            session: Some("abc".to_string()),
+            ..Config::default()
+        };
+        (Client::new(&config), config)
+    }
+
+    #[test]
+    fn test_download_and_cache() {
+        let dir = TempDir::new("fetch-cache");
+        let server = FakeServer::new(|_| (200, "1\n2\n3\n".to_string()));
+        let (client, config) = setup(&dir, &server);
+
+        let fetched = fetch_input(&client, &config, 2022, 3, false).unwrap();
+        assert_eq!(
+            fetched,
+            Fetched::Downloaded(dir.path().join("input/2022/03.txt"))
+        );
+        assert_eq!(dir.read("input/2022/03.txt"), "1\n2\n3\n");
+        assert_eq!(server.requests()[0].path, "/2022/day/3/input");
+
+        let fetched = fetch_input(&client, &config, 2022, 3, false).unwrap();
+        assert_eq!(
+            fetched,
+            Fetched::Cached(dir.path().join("input/2022/03.txt"))
+        );
+        assert_eq!(server.requests().len(), 1);
+
+        fetch_input(&client, &config, 2022, 3, true).unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_error_status_keeps_existing_input() {
+        let dir = TempDir::new("fetch-status");
+        dir.write("input/2022/04.txt", "old\n");
+        let server = FakeServer::new(|_| (500, "Internal Server Error".to_string()));
+        let (client, config) = setup(&dir, &server);
+
+        let error = fetch_input(&client, &config, 2022, 4, true).unwrap_err();
+
+        assert!(matches!(error, FetchError::Status(500, _)));
+        assert_eq!(dir.read("input/2022/04.txt"), "old\n");
+    }
+
+    #[test]
+    fn test_fetch_puzzle() {
+        let dir = TempDir::new("fetch-puzzle");
+        let server = FakeServer::new(|request| match request.path.as_str() {
+            "/2022/day/1" => (200, "<main><article>Day 1</article></main>".to_string()),
+            _ => (200, "<main>Not yet</main>".to_string()),
+        });
+        let (client, config) = setup(&dir, &server);
+
+        let fetched = fetch_puzzle(&client, &config, 2022, 1, false).unwrap();
+        let path = dir.path().join("puzzles/2022/01.html");
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(
+            fetch_puzzle(&client, &config, 2022, 1, false).unwrap(),
+            Fetched::Cached(path)
+        );
+        assert_eq!(server.requests().len(), 1);
+
+        let error = fetch_puzzle(&client, &config, 2022, 2, false).unwrap_err();
+        assert!(matches!(error, FetchError::Rejected(_)));
+        assert!(save_puzzle(&config, 2022, 2, "<article>Day 2</article>").is_ok());
+        assert_eq!(dir.read("puzzles/2022/02.html"), "<article>Day 2</article>");
+    }
+
+    #[test]
+    fn test_rejects_error_pages() {
+        let dir = TempDir::new("fetch-reject");
+        let server = FakeServer::new(|request| match request.path.as_str() {
+            "/2022/day/1/input" => (200, "<!DOCTYPE html>\n<html></html>".to_string()),
+            "/2022/day/2/input" => (
+                200,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                    .to_string(),
+            ),
+            _ => (200, String::new()),
+        });
+        let (client, config) = setup(&dir, &server);
+
+        for day in 1..=3 {
+            let error = fetch_input(&client, &config, 2022, day, false).unwrap_err();
+            assert!(matches!(error, FetchError::Rejected(_)), "{}", error);
+            assert!(!config.input_path(2022, day).exists());
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::generate::{self, Rng};
+use crate::solution::{Part, Solver};
+
+/// Parses `data` as `solver`'s input and solves both parts. Bad input should
+/// come back as an error; this panics only if the solver does.
+pub fn check(solver: &dyn Solver, data: &[u8]) {
+    let input = String::from_utf8_lossy(data);
+    if let Ok(parsed) = solver.parse(&input) {
+        for part in Part::ALL {
+            let _ = parsed.solve(part);
+        }
+    }
+}
+
+/// The entry point for the `days` fuzz target: the first byte picks one of
+/// the registered days, of any year, and the rest is its input. With no days
+/// registered there is nothing to check.
+pub fn check_any(data: &[u8]) {
+    let Some((&day, data)) = data.split_first() else {
+        return;
+    };
+    let count = crate::days().count();
+    if count == 0 {
+        return;
+    }
+    if let Some(solver) = crate::days().nth(day as usize % count) {
+        check(solver, data);
+    }
+}
+
+const INTERESTING: &[&str] = &[
+    "",
+    "\n",
+    "\n\n",
+    " ",
+    "-",
+    "0",
+    "-1",
+    "4294967295",
+    "18446744073709551616",
+    "9223372036854775807",
+    "-9223372036854775808",
+    "old",
+    "$ cd ..",
+    "$ cd /",
+    "dir",
+    "addx",
+    "noop",
+    "move",
+    "Monkey",
+    "x=",
+    "\u{feff}",
+    "é",
+];
+
+/// A random edit of `input`: bytes are flipped, deleted or repeated, lines
+/// swapped, and numbers replaced by extreme values.
+pub fn mutate(rng: &mut Rng, input: &str) -> Vec<u8> {
+    let mut lines = input
+        .split('\n')
+        .map(|line| line.as_bytes().to_vec())
+        .collect::<Vec<_>>();
+
+    for _ in 0..rng.range(1..=4) {
+        let i = rng.below(lines.len());
+        match rng.below(6) {
+            0 => {
+                let line = &mut lines[i];
+                if !line.is_empty() {
+                    let at = rng.below(line.len());
+                    line[at] = rng.next_u64() as u8;
+                }
+            }
+            1 => {
+                let line = &mut lines[i];
+                if !line.is_empty() {
+                    let at = rng.below(line.len());
+                    line.truncate(at);
+                }
+            }
+            2 => {
+                let line = lines[i].clone();
+                lines.insert(rng.below(lines.len() + 1), line);
+            }
+            3 => {
+                let j = rng.below(lines.len());
+                lines.swap(i, j);
+            }
+            4 => {
+                lines.remove(i);
+                if lines.is_empty() {
+                    lines.push(Vec::new());
+                }
+            }
+            _ => {
+                let text = String::from_utf8_lossy(&lines[i]).into_owned();
+                let words = text.split(' ').collect::<Vec<_>>();
+                let at = rng.below(words.len());
+                let mut words = words.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+                words[at] = rng.pick(INTERESTING).to_string();
+                lines[i] = words.join(" ").into_bytes();
+            }
+        }
+    }
+
+    lines.join(&b'\n')
+}
+
+/// Runs `rounds` mutations of generated inputs through every day.
+pub fn smoke(seed: u64, rounds: usize) {
+    let mut rng = Rng::new(seed);
+    for solver in crate::days() {
+        for _ in 0..rounds {
+            let size = rng.range(1..=20) as usize;
+            let input = generate::generate(solver.year(), solver.day(), size, rng.next_u64())
+                .unwrap_or_default();
+            check(solver, &mutate(&mut rng, &input));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mutations() {
+        smoke(2022, 200);
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        for solver in crate::days() {
+            for input in INTERESTING {
+                check(solver, input.as_bytes());
+            }
+        }
+    }
+}
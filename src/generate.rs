@@ -0,0 +1,419 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use crate::ocr;
+
+/// A small deterministic random number generator (SplitMix64), so that the
+/// same seed always generates the same input.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end);
+        let span = end.abs_diff(start).wrapping_add(1);
+        match span {
+            0 => self.next_u64() as i64,
+            span => start.wrapping_add((self.next_u64() % span) as i64),
+        }
+    }
+
+    /// An index below `n`, which must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0);
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// The input generator for `day`. `size` is roughly the number of lines or
+/// records to generate; see each generator for what it means there.
+pub fn generator(year: u16, day: u8) -> Option<Generator> {
+    let generator: Generator = match (year, day) {
+        (2022, 1) => day01,
+        (2022, 2) => day02,
+        (2022, 3) => day03,
+        (2022, 4) => day04,
+        (2022, 5) => day05,
+        (2022, 6) => day06,
+        (2022, 7) => day07,
+        (2022, 8) => day08,
+        (2022, 9) => day09,
+        (2022, 10) => day10,
+        (2022, 11) => day11,
+        (2022, 15) => day15,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+pub fn generate(year: u16, day: u8, size: usize, seed: u64) -> Option<String> {
+    generator(year, day).map(|generator| generator(&mut Rng::new(seed), size))
+}
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn word(rng: &mut Rng, letters: &[u8], len: RangeInclusive<i64>) -> String {
+    let len = rng.range(len);
+    (0..len).map(|_| rng.pick(letters) as char).collect()
+}
+
+/// `size` elves carrying a few snacks each.
+fn day01(rng: &mut Rng, size: usize) -> String {
+    let elves = (0..size.max(1))
+        .map(|_| {
+            let snacks = (0..rng.range(1..=6)).map(|_| rng.range(1000..=60000).to_string());
+            snacks.collect::<Vec<_>>().join("\n")
+        })
+        .collect::<Vec<_>>();
+    elves.join("\n\n") + "\n"
+}
+
+/// `size` rounds of rock paper scissors.
+fn day02(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.pick(&['A', 'B', 'C']),
+                rng.pick(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
+
+/// About `size` rucksacks, in groups of three that share a badge.
+fn day03(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.div_ceil(3).max(1) {
+        let badge = rng.pick(LETTERS) as char;
+        for _ in 0..3 {
+            let half = rng.range(2..=16) as usize;
+            let common = rng.pick(LETTERS) as char;
+            let mut left = vec![common, badge];
+            let mut right = vec![common];
+            left.extend((2..half).map(|_| rng.pick(LETTERS) as char));
+            right.extend((1..half).map(|_| rng.pick(LETTERS) as char));
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            input.extend(left.into_iter().chain(right));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+/// `size` pairs of section assignments.
+fn day04(rng: &mut Rng, size: usize) -> String {
+    let assignment = |rng: &mut Rng| {
+        let start = rng.range(1..=99);
+        format!("{}-{}", start, rng.range(start..=99))
+    };
+    (0..size.max(1))
+        .map(|_| format!("{},{}\n", assignment(rng), assignment(rng)))
+        .collect()
+}
+
+/// A drawing of up to 9 stacks followed by `size` moves.
+fn day05(rng: &mut Rng, size: usize) -> String {
+    let count = rng.range(3..=9) as usize;
+    let mut stacks = (0..count)
+        .map(|_| word(rng, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ", 1..=8).into_bytes())
+        .collect::<Vec<_>>();
+
+    let mut input = String::new();
+    let top = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    for level in (0..top).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(&c) => format!("[{}]", c as char),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<_>>();
+        input.push_str(&row.join(" "));
+        input.push('\n');
+    }
+    let numbers = (1..=count).map(|n| format!(" {} ", n)).collect::<Vec<_>>();
+    input.push_str(&numbers.join(" "));
+    input.push_str("\n\n");
+
+    for _ in 0..size.max(1) {
+        let full = (0..count)
+            .filter(|&i| !stacks[i].is_empty())
+            .collect::<Vec<_>>();
+        let src = rng.pick(&full);
+        let dst = (src + 1 + rng.below(count - 1)) % count;
+        let qty = rng.range(1..=stacks[src].len() as i64) as usize;
+
+        let height = stacks[src].len();
+        let moved = stacks[src].split_off(height - qty);
+        stacks[dst].extend(moved);
+        input.push_str(&format!("move {} from {} to {}\n", qty, src + 1, dst + 1));
+    }
+    input
+}
+
+/// A signal of `size` characters with a start-of-message marker somewhere.
+fn day06(rng: &mut Rng, size: usize) -> String {
+    let len = size.max(14);
+    let mut signal = word(rng, b"abcd", len as i64..=len as i64).into_bytes();
+
+    let mut marker = LOWERCASE.to_vec();
+    rng.shuffle(&mut marker);
+    let at = rng.below(len - 13);
+    signal[at..at + 14].copy_from_slice(&marker[..14]);
+
+    String::from_utf8(signal).expect("letters are ASCII") + "\n"
+}
+
+/// A shell transcript exploring a filesystem of about `size` entries.
+fn day07(rng: &mut Rng, size: usize) -> String {
+    fn explore(
+        rng: &mut Rng,
+        input: &mut String,
+        names: &mut HashSet<String>,
+        remaining: &mut usize,
+        max_file: i64,
+        depth: usize,
+    ) {
+        input.push_str("$ ls\n");
+
+        let mut dirs = Vec::new();
+        for _ in 0..rng.range(1..=6) {
+            if *remaining == 0 {
+                break;
+            }
+            *remaining -= 1;
+
+            let mut name = word(rng, LOWERCASE, 1..=8);
+            if depth < 8 && rng.chance(1, 3) {
+                if names.insert(name.clone()) {
+                    input.push_str(&format!("dir {}\n", name));
+                    dirs.push(name);
+                }
+            } else {
+                if rng.chance(1, 2) {
+                    name.push('.');
+                    name.push_str(&word(rng, LOWERCASE, 1..=3));
+                }
+                if names.insert(name.clone()) {
+                    input.push_str(&format!("{} {}\n", rng.range(1..=max_file), name));
+                }
+            }
+        }
+
+        for dir in dirs {
+            input.push_str(&format!("$ cd {}\n", dir));
+            explore(
+                rng,
+                input,
+                &mut HashSet::new(),
+                remaining,
+                max_file,
+                depth + 1,
+            );
+            input.push_str("$ cd ..\n");
+        }
+    }
+
+    // Keep the total under the 70000000 bytes of the disk.
+    let size = size.max(1);
+    let max_file = (60_000_000 / size).clamp(1, 300_000) as i64;
+    let mut input = String::from("$ cd /\n");
+    let (mut root, mut remaining) = (HashSet::new(), size);
+    while remaining > 0 {
+        explore(rng, &mut input, &mut root, &mut remaining, max_file, 0);
+        if remaining > 0 {
+            input.push_str("$ cd /\n");
+        }
+    }
+    input
+}
+
+/// A `size` by `size` forest.
+fn day08(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1) as i64;
+    (0..size)
+        .map(|_| word(rng, b"0123456789", size..=size) + "\n")
+        .collect()
+}
+
+/// `size` rope motions.
+fn day09(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.pick(&['U', 'D', 'L', 'R']),
+                rng.range(1..=20)
+            )
+        })
+        .collect()
+}
+
+/// A program drawing one CRT frame of eight random letters for every 240 of
+/// the `size` cycles, or at least one. Each addx lasts two cycles, so x picks
+/// the pixels lit in a pair of columns; it starts at 1, which lights the first
+/// pair, so the first letter must start that way too.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let letters = ocr::letters().collect::<Vec<_>>();
+    let first = letters
+        .iter()
+        .copied()
+        .filter(|&c| {
+            let glyph = ocr::draw(&c.to_string()).unwrap();
+            glyph.get(0, 0) == Some(true) && glyph.get(1, 0) == Some(true)
+        })
+        .collect::<Vec<_>>();
+
+    let mut targets = Vec::new();
+    for frame in 0..size.div_ceil(240).max(1) {
+        let text = (0..8)
+            .map(|i| match (frame, i) {
+                (0, 0) => rng.pick(&first),
+                _ => rng.pick(&letters),
+            })
+            .collect::<String>();
+        let bitmap = ocr::draw(&text).unwrap();
+        for row in bitmap.rows() {
+            for (col, pair) in (0..).step_by(2).zip(row.chunks(2)) {
+                targets.push(match (pair[0], pair[1]) {
+                    (true, true) => col + 1,
+                    (true, false) => col - 1,
+                    (false, true) => col + 2,
+                    (false, false) => col + 3,
+                });
+            }
+        }
+    }
+
+    let mut input = String::new();
+    let mut x = 1;
+    for &target in &targets[1..] {
+        input.push_str(&format!("addx {}\n", target - x));
+        x = target;
+    }
+    input.push_str("addx 0\n");
+    input
+}
+
+/// `size` monkeys, between 2 and 9. Monkeys other than the first only throw
+/// to monkeys before them, and worry levels grow slowly, so that part 1 never
+/// overflows.
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 9);
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+
+    let monkeys = (0..count)
+        .map(|i| {
+            let items = (0..rng.range(0..=6))
+                .map(|_| rng.range(50..=99).to_string())
+                .collect::<Vec<_>>();
+            let operation = match rng.below(3) {
+                0 => format!("* {}", rng.range(2..=7)),
+                1 => format!("+ {}", rng.range(1..=8)),
+                _ => "+ old".to_string(),
+            };
+            let target = |rng: &mut Rng| match i {
+                0 => rng.range(1..=count as i64 - 1),
+                _ => rng.range(0..=i as i64 - 1),
+            };
+
+            let mut monkey = format!("Monkey {}:\n  Starting items:", i);
+            if !items.is_empty() {
+                monkey.push_str(&format!(" {}", items.join(", ")));
+            }
+            monkey.push_str(&format!(
+                "\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                operation,
+                primes[i],
+                target(rng),
+                target(rng)
+            ));
+            monkey
+        })
+        .collect::<Vec<_>>();
+    monkeys.join("\n")
+}
+
+/// `size` sensors, most of them reaching row 2000000.
+fn day15(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let (x, y) = (rng.range(0..=4_000_000), rng.range(1_000_000..=3_000_000));
+            let (bx, by) = (
+                x + rng.range(-500_000..=500_000),
+                y + rng.range(-500_000..=500_000),
+            );
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                x, y, bx, by
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::checks;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(42);
+        let values = (0..100).map(|_| rng.range(-3..=3)).collect::<Vec<_>>();
+
+        assert!(values.iter().all(|v| (-3..=3).contains(v)));
+        assert!((-3..=3).all(|v| values.contains(&v)));
+        assert_eq!(Rng::new(42).next_u64(), Rng::new(42).next_u64());
+        assert_ne!(Rng::new(42).next_u64(), Rng::new(43).next_u64());
+        Rng::new(0).range(i64::MIN..=i64::MAX);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_eq!(generate(2022, 5, 20, 7), generate(2022, 5, 20, 7));
+        assert_ne!(generate(2022, 5, 20, 7), generate(2022, 5, 20, 8));
+        assert_eq!(generate(2022, 12, 20, 7), None);
+        assert_eq!(generate(2021, 5, 20, 7), None);
+    }
+
+    #[test]
+    fn test_inputs_solve() {
+        checks::generators(&crate::days().collect::<Vec<_>>()).assert_passed();
+    }
+}
@@ -0,0 +1,444 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The signed integer types points can be built from.
+pub trait Coord:
+    Copy
+    + Ord
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i32, i64, isize);
+
+/// A point or vector in 2D, with `y` growing downwards as in puzzle maps.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A difference between two points.
+pub type Vector<T = i64> = Point<T>;
+
+impl<T: Coord> Point<T> {
+    pub const ORIGIN: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// The taxicab distance, `|dx| + |dy|`.
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The chessboard distance, `max(|dx|, |dy|)`.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Whether `other` is this point or one of its 8 neighbours.
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        self.chebyshev(other) <= T::ONE
+    }
+
+    /// Each component reduced to -1, 0 or 1: the single step that moves most
+    /// directly along this vector.
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotates a quarter turn clockwise about the origin, as seen on a map.
+    pub fn rotate_right(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn anticlockwise about the origin, as seen on a map.
+    pub fn rotate_left(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self + d.vector())
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction::steps8().map(move |step| self + step)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Coord> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coord> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coord> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coord> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Coord> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A point or vector in 3D.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point3<T> {
+    pub const ORIGIN: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// Quarter turns about each axis, following the right-hand rule.
+    pub fn rotate_x(&self) -> Self {
+        Self::new(self.x, -self.z, self.y)
+    }
+
+    pub fn rotate_y(&self) -> Self {
+        Self::new(self.z, self.y, -self.x)
+    }
+
+    pub fn rotate_z(&self) -> Self {
+        Self::new(-self.y, self.x, self.z)
+    }
+
+    /// The 6 points sharing a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            (one, zero, zero),
+            (-one, zero, zero),
+            (zero, one, zero),
+            (zero, -one, zero),
+            (zero, zero, one),
+            (zero, zero, -one),
+        ]
+        .into_iter()
+        .map(move |(dx, dy, dz)| self + Self::new(dx, dy, dz))
+    }
+}
+
+impl<T: Coord> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coord> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Coord> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Coord> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// A compass direction on a map, where up is north.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Reads `U`/`R`/`D`/`L`, `N`/`E`/`S`/`W` or `^`/`>`/`v`/`<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' | '^' => Some(Self::Up),
+            'R' | 'E' | '>' => Some(Self::Right),
+            'D' | 'S' | 'v' => Some(Self::Down),
+            'L' | 'W' | '<' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    pub fn vector<T: Coord>(self) -> Vector<T> {
+        let one = T::ONE;
+        match self {
+            Self::Up => Point::new(T::ZERO, -one),
+            Self::Right => Point::new(one, T::ZERO),
+            Self::Down => Point::new(T::ZERO, one),
+            Self::Left => Point::new(-one, T::ZERO),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The steps to each of a point's 8 neighbours, clockwise from up.
+    pub fn steps8<T: Coord>() -> impl Iterator<Item = Vector<T>> {
+        Self::ALL.into_iter().flat_map(|d| {
+            let step = d.vector();
+            [step, step + d.turn_right().vector()]
+        })
+    }
+}
+
+/// The smallest axis-aligned rectangle holding a set of points, with both
+/// corners inclusive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BoundingBox<T = i64> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Coord> BoundingBox<T> {
+    pub fn new(point: Point<T>) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The box around `points`, or `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for point in points {
+            bounds.include(point);
+        }
+        Some(bounds)
+    }
+
+    pub fn include(&mut self, point: Point<T>) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut bounds = *self;
+        bounds.include(other.min);
+        bounds.include(other.max);
+        bounds
+    }
+
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(2, 18);
+        let b = Point::new(-2, 15);
+
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert!(!a.is_adjacent(&b));
+        assert!(a.is_adjacent(&Point::new(3, 17)));
+        assert_eq!((b - a).signum(), Point::new(-1, -1));
+
+        let c = Point3::new(1, 2, 3);
+        assert_eq!(c.manhattan(&Point3::ORIGIN), 6);
+        assert_eq!(c.chebyshev(&Point3::ORIGIN), 3);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p: Point<i32> = Point::ORIGIN;
+        p += Direction::Right.vector() * 3;
+        p -= Direction::Up.vector();
+
+        assert_eq!(p, Point::new(3, 1));
+        assert_eq!(-p, Point::new(-3, -1));
+        assert_eq!(p.to_string(), "(3, 1)");
+    }
+
+    #[test]
+    fn test_rotation() {
+        let up: Vector<i64> = Direction::Up.vector();
+
+        assert_eq!(up.rotate_right(), Direction::Right.vector());
+        assert_eq!(up.rotate_left(), Direction::Left.vector());
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+
+        let p = Point3::new(1, 2, 3);
+        assert_eq!(p.rotate_z(), Point3::new(-2, 1, 3));
+        assert_eq!(p.rotate_x().rotate_x().rotate_x().rotate_x(), p);
+        assert_eq!(p.rotate_y().rotate_y(), Point3::new(-1, 2, -3));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point::new(0_i64, 0);
+
+        assert_eq!(p.neighbours4().count(), 4);
+        assert!(p.neighbours8().all(|n| n.chebyshev(&p) == 1));
+        assert_eq!(Point3::new(0, 0, 0).neighbours6().count(), 6);
+    }
+
+    #[test]
+    fn test_direction_from_char() {
+        assert_eq!(Direction::from_char('U'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('W'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('X'), None);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let bounds =
+            BoundingBox::from_points([Point::new(2, 3), Point::new(-1, 5), Point::new(0, 0)])
+                .unwrap();
+
+        assert_eq!(bounds.min, Point::new(-1, 0));
+        assert_eq!(bounds.max, Point::new(2, 5));
+        assert_eq!((bounds.width(), bounds.height()), (4, 6));
+        assert!(bounds.contains(&Point::new(0, 4)));
+        assert!(!bounds.contains(&Point::new(3, 4)));
+        assert_eq!(
+            bounds.union(&BoundingBox::new(Point::new(5, -2))).max,
+            Point::new(5, 5)
+        );
+        assert_eq!(BoundingBox::<i64>::from_points([]), None);
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt;
+use std::time::Duration;
+
+use ureq::Agent;
+
+use crate::config::Config;
+
+#[derive(Debug)]
+pub enum HttpError {
+    NoSession,
+    Transport(String),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSession => write!(
+                f,
+                "no session cookie, set AOC_SESSION or save it to the session file"
+            ),
+            Self::Transport(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<ureq::Error> for HttpError {
+    fn from(e: ureq::Error) -> Self {
+        Self::Transport(e.to_string())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// An authenticated client for the Advent of Code site at `base_url`.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(config.user_agent.as_str())
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: config.base_url.clone(),
+            session: config.session.clone(),
+        }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn cookie(&self) -> Result<String, HttpError> {
+        match &self.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => Err(HttpError::NoSession),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<Response, HttpError> {
+        let mut response = self
+            .agent
+            .get(self.url(path))
+            .header("Cookie", self.cookie()?)
+            .call()?;
+
+        Ok(Response {
+            status: response.status().as_u16(),
+            body: response.body_mut().read_to_string()?,
+        })
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, HttpError> {
+        let mut response = self
+            .agent
+            .post(self.url(path))
+            .header("Cookie", self.cookie()?)
+            .send_form(form.iter().copied())?;
+
+        Ok(Response {
+            status: response.status().as_u16(),
+            body: response.body_mut().read_to_string()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::FakeServer;
+
+    fn config(url: &str, session: Option<&str>) -> Config {
+        Config {
+            base_url: url.to_string(),
+            session: session.map(str::to_string),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn test_get_sends_session_and_user_agent() {
+        let server = FakeServer::new(|_| (200, "hello".to_string()));
+        let client = Client::new(&config(&server.url, Some("abc")));
+
+        let response = client.get("/2022/day/1/input").unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "hello");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/2022/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+        assert!(requests[0]
+            .header("user-agent")
+            .unwrap()
+            .contains("aoc-2021"));
+    }
+
+    #[test]
+    fn test_error_status_is_not_an_error() {
+        let server = FakeServer::new(|_| (404, "404 Not Found".to_string()));
+        let client = Client::new(&config(&server.url, Some("abc")));
+
+        assert_eq!(client.get("/missing").unwrap().status, 404);
+    }
+
+    #[test]
+    fn test_post_form() {
+        let server = FakeServer::new(|request| (200, request.body.clone()));
+        let client = Client::new(&config(&server.url, Some("abc")));
+
+        let response = client
+            .post_form("/2022/day/1/answer", &[("level", "1"), ("answer", "a b")])
+            .unwrap();
+
+        assert_eq!(response.body, "level=1&answer=a+b");
+        assert_eq!(server.requests()[0].method, "POST");
+    }
+
+    #[test]
+    fn test_requires_session() {
+        let client = Client::new(&config("http://127.0.0.1:9", None));
+
+        assert!(matches!(client.get("/"), Err(HttpError::NoSession)));
+    }
+}
@@ -0,0 +1,239 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// Something an editor did to a puzzle input that [`Normalized`] undid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Fix {
+    ByteOrderMark,
+    CrlfLineEndings,
+    TrailingWhitespace,
+    TrailingBlankLines,
+}
+
+impl fmt::Display for Fix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ByteOrderMark => write!(f, "removed a byte order mark"),
+            Self::CrlfLineEndings => write!(f, "converted CRLF line endings"),
+            Self::TrailingWhitespace => write!(f, "trimmed trailing whitespace"),
+            Self::TrailingBlankLines => write!(f, "removed trailing blank lines"),
+        }
+    }
+}
+
+/// A puzzle input as every day expects it: `\n` line endings, no byte order
+/// mark, no whitespace at the end of lines and exactly one newline at the end.
+///
+/// No line is added or removed before the last non-blank one and no column
+/// shifts, so errors in the normalized text point at the same place in the
+/// original as an editor shows it.
+#[derive(Clone, Debug)]
+pub struct Normalized<'a> {
+    original: &'a str,
+    text: Cow<'a, str>,
+    fixes: Vec<Fix>,
+}
+
+impl<'a> Normalized<'a> {
+    pub fn new(original: &'a str) -> Self {
+        let mut fixes = Vec::new();
+
+        let input = match original.strip_prefix('\u{feff}') {
+            Some(input) => {
+                fixes.push(Fix::ByteOrderMark);
+                input
+            }
+            None => original,
+        };
+        if input.contains("\r\n") {
+            fixes.push(Fix::CrlfLineEndings);
+        }
+
+        let mut lines = input.lines().collect::<Vec<_>>();
+        if lines.iter().any(|line| line.trim_end().len() != line.len()) {
+            fixes.push(Fix::TrailingWhitespace);
+        }
+        let content = lines
+            .iter()
+            .rposition(|line| !line.trim_end().is_empty())
+            .map_or(0, |last| last + 1);
+        if content < lines.len() {
+            fixes.push(Fix::TrailingBlankLines);
+            lines.truncate(content);
+        }
+
+        let mut text = String::with_capacity(input.len());
+        for line in lines {
+            text.push_str(line.trim_end());
+            text.push('\n');
+        }
+
+        let text = if text == input {
+            Cow::Borrowed(input)
+        } else {
+            Cow::Owned(text)
+        };
+        Self {
+            original,
+            text,
+            fixes,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn original(&self) -> &'a str {
+        self.original
+    }
+
+    /// What was changed, apart from adding a missing final newline.
+    pub fn fixes(&self) -> &[Fix] {
+        &self.fixes
+    }
+}
+
+/// Reads a stream line by line, normalizing it the same way as
+/// [`Normalized`] without holding more than one line in memory.
+pub struct Lines<R> {
+    reader: R,
+    line: String,
+    /// Lines read so far, including `line`.
+    read: usize,
+    /// Blank lines read before `line` that haven't been returned yet. They
+    /// are dropped if no other line follows.
+    blank: usize,
+    /// Whether `line` has yet to be returned.
+    pending: bool,
+}
+
+impl<R: BufRead> Lines<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            read: 0,
+            blank: 0,
+            pending: false,
+        }
+    }
+
+    /// The next line and its 1-based number, or `None` at the end of input.
+    pub fn next_line(&mut self) -> io::Result<Option<(usize, &str)>> {
+        while !self.pending {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line)? == 0 {
+                self.blank = 0;
+                return Ok(None);
+            }
+            self.read += 1;
+
+            if self.read == 1 && self.line.starts_with('\u{feff}') {
+                self.line.drain(..'\u{feff}'.len_utf8());
+            }
+            if self.line.trim_end().is_empty() {
+                self.blank += 1;
+            } else {
+                self.pending = true;
+            }
+        }
+
+        if self.blank > 0 {
+            let number = self.read - self.blank;
+            self.blank -= 1;
+            return Ok(Some((number, "")));
+        }
+        self.pending = false;
+        Ok(Some((self.read, self.line.trim_end())))
+    }
+}
+
+/// Shorthand for `Normalized::new(input).text()` when the fixes don't matter.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    Normalized::new(input).text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{DayError, ParseError};
+
+    #[test]
+    fn test_clean_input_is_borrowed() {
+        let normalized = Normalized::new("1\n\n2\n");
+
+        assert!(matches!(normalized.text, Cow::Borrowed(_)));
+        assert!(normalized.fixes().is_empty());
+        assert_eq!(normalize("1\n2"), "1\n2\n");
+    }
+
+    #[test]
+    fn test_fixes() {
+        let original = "\u{feff}1000\r\n2000  \r\n\r\n3000\r\n\r\n \n";
+        let normalized = Normalized::new(original);
+
+        assert_eq!(normalized.text(), "1000\n2000\n\n3000\n");
+        assert_eq!(normalized.original(), original);
+        assert_eq!(
+            normalized.fixes(),
+            [
+                Fix::ByteOrderMark,
+                Fix::CrlfLineEndings,
+                Fix::TrailingWhitespace,
+                Fix::TrailingBlankLines
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut lines = Lines::new("\u{feff}a \r\n\r\nb\r\n \r\n\n".as_bytes());
+        let mut read = Vec::new();
+        while let Some((number, line)) = lines.next_line().unwrap() {
+            read.push((number, line.to_string()));
+        }
+
+        assert_eq!(
+            read,
+            [
+                (1, "a".to_string()),
+                (2, String::new()),
+                (3, "b".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blank_input() {
+        assert_eq!(normalize(""), "");
+        assert_eq!(normalize("\n\n"), "");
+    }
+
+    #[test]
+    fn test_days_see_normalized_input() {
+        let input = "\u{feff}1000\r\n2000\r\n\r\n4000\r\n\r\n";
+        let solver = crate::find(2022, 1).unwrap();
+
+        assert_eq!(
+            solver.solve(input, crate::solution::Part::One),
+            Ok(4000.into())
+        );
+        assert_eq!(crate::y2022::day01::part1(input), Ok(4000.into()));
+    }
+
+    #[test]
+    fn test_error_positions_match_original() {
+        let error = crate::y2022::day04::part1("\u{feff}2-4,6-8  \r\n2-3,4\r\n").unwrap_err();
+
+        assert!(matches!(
+            error,
+            DayError::Parse(ParseError {
+                line: 2,
+                column: 6,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,469 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::solution::Part;
+use crate::table::align;
+
+/// A star as recorded by the site: when it was earned, and in what order
+/// across the leaderboard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    #[serde(rename = "get_star_ts")]
+    pub time: u64,
+    #[serde(rename = "star_index", default)]
+    pub index: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayStars {
+    pub part1: Option<Star>,
+    pub part2: Option<Star>,
+}
+
+impl DayStars {
+    pub fn get(&self, part: Part) -> Option<&Star> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    /// The score the site gave, for comparison with [`Scoring::Local`].
+    pub local_score: u64,
+    pub days: BTreeMap<u8, DayStars>,
+}
+
+impl Member {
+    /// The member's name, or what the site shows for anonymous users.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn stars(&self) -> usize {
+        self.days
+            .values()
+            .flat_map(|day| Part::ALL.map(|part| day.get(part)))
+            .flatten()
+            .count()
+    }
+
+    pub fn star(&self, day: u8, part: Part) -> Option<&Star> {
+        self.days.get(&day).and_then(|stars| stars.get(part))
+    }
+}
+
+/// How members are ordered by [`Leaderboard::ranking`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Scoring {
+    /// The site's own: each star is worth one point per member, less one for
+    /// every member who got it sooner.
+    Local,
+    /// The number of stars, earliest last star first on a tie.
+    Stars,
+    /// The most stars, then the least time taken over them, counting each day
+    /// from when its puzzle unlocked to the member's last star of the day.
+    Time,
+}
+
+impl fmt::Display for Scoring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local => write!(f, "local"),
+            Self::Stars => write!(f, "stars"),
+            Self::Time => write!(f, "time"),
+        }
+    }
+}
+
+impl FromStr for Scoring {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(Self::Local),
+            "stars" => Ok(Self::Stars),
+            "time" => Ok(Self::Time),
+            _ => Err(format!(
+                "invalid scoring '{}', expected local, stars or time",
+                s
+            )),
+        }
+    }
+}
+
+/// A member's place under some [`Scoring`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Standing<'a> {
+    pub member: &'a Member,
+    pub stars: usize,
+    /// Points, stars, or seconds taken, depending on the scoring.
+    pub score: u64,
+}
+
+#[derive(Deserialize)]
+struct RawLeaderboard {
+    event: String,
+    members: BTreeMap<String, RawMember>,
+}
+
+#[derive(Deserialize)]
+struct RawMember {
+    id: u64,
+    name: Option<String>,
+    #[serde(default)]
+    local_score: u64,
+    #[serde(default)]
+    completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+/// A private leaderboard, as served in JSON from the site's
+/// `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    /// Ordered by id.
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::parse(&json).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let raw: RawLeaderboard = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let year = raw
+            .event
+            .parse()
+            .map_err(|_| format!("{:?} is not a year", raw.event))?;
+
+        let mut members = Vec::new();
+        for member in raw.members.into_values() {
+            let mut days = BTreeMap::new();
+            for (day_key, parts) in member.completion_day_level {
+                let day = day_key
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|day| (1..=25).contains(day))
+                    .ok_or_else(|| format!("{:?} is not a day", day_key))?;
+                let mut stars = DayStars::default();
+                for (part_key, star) in parts {
+                    match part_key.as_str() {
+                        "1" => stars.part1 = Some(star),
+                        "2" => stars.part2 = Some(star),
+                        _ => return Err(format!("{:?} is not a part of day {}", part_key, day)),
+                    }
+                }
+                days.insert(day, stars);
+            }
+            members.push(Member {
+                id: member.id,
+                name: member.name,
+                local_score: member.local_score,
+                days,
+            });
+        }
+        members.sort_by_key(|member| member.id);
+
+        Ok(Self { year, members })
+    }
+
+    /// The days anyone has a star for.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days = self
+            .members
+            .iter()
+            .flat_map(|member| member.days.keys().copied())
+            .collect::<Vec<_>>();
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    /// How long after `day` unlocked `member` got the star for `part`.
+    pub fn solve_time(&self, member: &Member, day: u8, part: Part) -> Option<u64> {
+        let star = member.star(day, part)?;
+        Some(star.time.saturating_sub(unlock_time(self.year, day)))
+    }
+
+    /// Every member, best first. Members who tie keep the order of their ids.
+    pub fn ranking(&self, scoring: Scoring) -> Vec<Standing<'_>> {
+        let mut standings = self
+            .members
+            .iter()
+            .map(|member| Standing {
+                member,
+                stars: member.stars(),
+                score: match scoring {
+                    Scoring::Local => self.local_score(member),
+                    Scoring::Stars => member.stars() as u64,
+                    Scoring::Time => self.total_time(member),
+                },
+            })
+            .collect::<Vec<_>>();
+
+        let last_star = |standing: &Standing| {
+            let member = standing.member;
+            let times = member
+                .days
+                .values()
+                .flat_map(|day| Part::ALL.map(|part| day.get(part)));
+            times
+                .flatten()
+                .map(|star| star.time)
+                .max()
+                .unwrap_or(u64::MAX)
+        };
+        match scoring {
+            Scoring::Local => standings.sort_by_key(|s| Reverse(s.score)),
+            Scoring::Stars => {
+                standings.sort_by_key(|s| (Reverse(s.stars), last_star(s)));
+            }
+            Scoring::Time => standings.sort_by_key(|s| (Reverse(s.stars), s.score)),
+        }
+        standings
+    }
+
+    fn local_score(&self, member: &Member) -> u64 {
+        let count = self.members.len() as u64;
+        let mut score = 0;
+        for (&day, stars) in &member.days {
+            for part in Part::ALL {
+                let Some(star) = stars.get(part) else {
+                    continue;
+                };
+                let sooner = self
+                    .members
+                    .iter()
+                    .filter_map(|other| other.star(day, part))
+                    .filter(|other| (other.time, other.index) < (star.time, star.index))
+                    .count() as u64;
+                score += count - sooner;
+            }
+        }
+        score
+    }
+
+    fn total_time(&self, member: &Member) -> u64 {
+        member
+            .days
+            .keys()
+            .filter_map(|&day| {
+                Part::ALL
+                    .iter()
+                    .filter_map(|&part| self.solve_time(member, day, part))
+                    .max()
+            })
+            .sum()
+    }
+
+    /// A table of the members in order under `scoring`.
+    pub fn ranking_table(&self, scoring: Scoring) -> String {
+        let heading = match scoring {
+            Scoring::Time => "Time",
+            _ => "Score",
+        };
+        let mut rows = vec![["#", "Member", "Stars", heading].map(String::from)];
+        for (i, standing) in self.ranking(scoring).iter().enumerate() {
+            let score = match scoring {
+                Scoring::Time if standing.stars == 0 => "-".to_string(),
+                Scoring::Time => format_duration(standing.score),
+                _ => standing.score.to_string(),
+            };
+            rows.push([
+                (i + 1).to_string(),
+                standing.member.display_name(),
+                standing.stars.to_string(),
+                score,
+            ]);
+        }
+        align(&rows)
+    }
+
+    /// A table of how long each member who has a star for `day` took over
+    /// each part, and the time between the two, fastest first.
+    pub fn day_table(&self, day: u8) -> String {
+        let mut times = self
+            .members
+            .iter()
+            .filter_map(|member| {
+                let part1 = self.solve_time(member, day, Part::One)?;
+                let part2 = self.solve_time(member, day, Part::Two);
+                Some((member, part1, part2))
+            })
+            .collect::<Vec<_>>();
+        times.sort_by_key(|&(_, part1, part2)| (part2.unwrap_or(u64::MAX), part1));
+
+        let mut rows = vec![["Member", "Part 1", "Part 2", "Delta"].map(String::from)];
+        for (member, part1, part2) in times {
+            let (part2, delta) = match part2 {
+                Some(part2) => (
+                    format_duration(part2),
+                    format_duration(part2.saturating_sub(part1)),
+                ),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            rows.push([member.display_name(), format_duration(part1), part2, delta]);
+        }
+        align(&rows)
+    }
+}
+
+/// When `day` of `year` unlocks, in seconds since the Unix epoch: midnight
+/// in the site's time zone, UTC-5.
+pub fn unlock_time(year: u16, day: u8) -> u64 {
+    days_from_civil(year as i64, 12, day as i64) as u64 * 86400 + 5 * 3600
+}
+
+/// The number of days from 1970-01-01 to the given date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Seconds as `h:mm:ss`, where the hours can pass 24.
+fn format_duration(seconds: u64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    // Day 1 of 2022 unlocked at 1669870800 and day 2 at 1669957200.
+    const JSON: &str = r#"{
+  "event": "2022",
+  "owner_id": 1,
+  "members": {
+    "2": {
+      "id": 2, "name": "Bob", "stars": 2, "local_score": 5, "global_score": 0,
+      "last_star_ts": 1669872300,
+      "completion_day_level": {
+        "1": {
+          "1": { "get_star_ts": 1669871100, "star_index": 10 },
+          "2": { "get_star_ts": 1669872300, "star_index": 40 }
+        }
+      }
+    },
+    "1": {
+      "id": 1, "name": "Alice", "stars": 3, "local_score": 8, "global_score": 0,
+      "last_star_ts": 1669958400,
+      "completion_day_level": {
+        "1": {
+          "1": { "get_star_ts": 1669871400, "star_index": 20 },
+          "2": { "get_star_ts": 1669871700, "star_index": 30 }
+        },
+        "2": { "1": { "get_star_ts": 1669958400, "star_index": 50 } }
+      }
+    },
+    "3": {
+      "id": 3, "name": null, "stars": 0, "local_score": 0, "global_score": 0,
+      "last_star_ts": 0, "completion_day_level": {}
+    }
+  }
+}"#;
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = Leaderboard::parse(JSON).unwrap();
+
+        assert_eq!(leaderboard.year, 2022);
+        assert_eq!(leaderboard.members.len(), 3);
+        assert_eq!(leaderboard.members[0].display_name(), "Alice");
+        assert_eq!(leaderboard.members[0].stars(), 3);
+        assert_eq!(leaderboard.members[2].display_name(), "(anonymous user #3)");
+        assert_eq!(leaderboard.days(), vec![1, 2]);
+
+        let bad = JSON.replace("\"2\": { \"1\"", "\"26\": { \"1\"");
+        assert_eq!(Leaderboard::parse(&bad).unwrap_err(), "\"26\" is not a day");
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = TempDir::new("leaderboard");
+        dir.write("board.json", JSON);
+
+        assert!(Leaderboard::load(&dir.path().join("board.json")).is_ok());
+        assert!(Leaderboard::load(&dir.path().join("missing.json")).is_err());
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2022, 1), 1669870800);
+        assert_eq!(unlock_time(2022, 25), 1671944400);
+    }
+
+    #[test]
+    fn test_ranking() {
+        let leaderboard = Leaderboard::parse(JSON).unwrap();
+        let scores = |scoring| {
+            leaderboard
+                .ranking(scoring)
+                .iter()
+                .map(|standing| (standing.member.id, standing.score))
+                .collect::<Vec<_>>()
+        };
+
+        // The computed local scores agree with the site's.
+        assert_eq!(scores(Scoring::Local), vec![(1, 8), (2, 5), (3, 0)]);
+        assert_eq!(scores(Scoring::Stars), vec![(1, 3), (2, 2), (3, 0)]);
+        assert_eq!(scores(Scoring::Time), vec![(1, 2100), (2, 1500), (3, 0)]);
+        assert_eq!("time".parse(), Ok(Scoring::Time));
+        assert!("fastest".parse::<Scoring>().is_err());
+    }
+
+    #[test]
+    fn test_tables() {
+        let leaderboard = Leaderboard::parse(JSON).unwrap();
+
+        assert_eq!(
+            leaderboard.ranking_table(Scoring::Time),
+            "#  Member               Stars  Time
+-  -------------------  -----  -------
+1  Alice                3      0:35:00
+2  Bob                  2      0:25:00
+3  (anonymous user #3)  0      -
+"
+        );
+        assert_eq!(
+            leaderboard.day_table(1),
+            "Member  Part 1   Part 2   Delta
+------  -------  -------  -------
+Alice   0:10:00  0:15:00  0:05:00
+Bob     0:05:00  0:25:00  0:20:00
+"
+        );
+        assert_eq!(
+            leaderboard.day_table(2),
+            "Member  Part 1   Part 2  Delta
+------  -------  ------  -----
+Alice   0:20:00  -       -
+"
+        );
+    }
+}
@@ -1,12 +1,89 @@
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day15;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod checks;
+pub mod config;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod fuzz;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod leaderboard;
+pub mod ocr;
+pub mod params;
+pub mod pattern;
+pub mod progress;
+pub mod puzzle;
+pub mod range_set;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod table;
+#[cfg(test)]
+mod test_util;
+
+pub mod y2021;
+pub mod y2022;
+
+use solution::Solver;
+
+/// The registered days of each year, ordered by year. Kept one year per line
+/// for `aoc new`, which adds to it.
+#[rustfmt::skip]
+pub static YEARS: &[(u16, &[&dyn Solver])] = &[
+    (2021, y2021::DAYS),
+    (2022, y2022::DAYS),
+];
+
+/// Every registered day, ordered by year and then by day.
+pub fn days() -> impl Iterator<Item = &'static dyn Solver> {
+    YEARS.iter().flat_map(|(_, days)| days.iter().copied())
+}
+
+/// The registered days of `year`, which is empty for a year without any.
+pub fn days_in(year: u16) -> &'static [&'static dyn Solver] {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map_or(&[], |(_, days)| days)
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn Solver> {
+    days_in(year)
+        .iter()
+        .copied()
+        .find(|solver| solver.day() == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_registry_sorted_and_unique() {
+        let days = days()
+            .map(|solver| (solver.year(), solver.day()))
+            .collect::<Vec<_>>();
+
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        for (year, days) in YEARS {
+            assert!(days.iter().all(|solver| solver.year() == *year));
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(
+            find(2022, 10).map(|solver| solver.title()),
+            Some("Cathode-Ray Tube")
+        );
+        assert!(find(2022, 25).is_none());
+        assert!(find(2021, 10).is_none());
+        assert!(days_in(2015).is_empty());
+    }
+}
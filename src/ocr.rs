@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::answer::Bitmap;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+/// Glyphs are separated by one blank column.
+const PITCH: usize = GLYPH_WIDTH + 1;
+
+/// The capital letters AoC draws in its 4x6 font, as far as they're known.
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// The frame isn't one row of glyphs.
+    Size { width: usize, height: usize },
+    /// The columns at which glyphs that aren't in the font start.
+    Unrecognized(Vec<usize>),
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Size { width, height } => write!(
+                f,
+                "a {}x{} frame is not a row of {}x{} glyphs",
+                width, height, GLYPH_WIDTH, GLYPH_HEIGHT
+            ),
+            Self::Unrecognized(columns) => {
+                let columns = columns.iter().map(usize::to_string).collect::<Vec<_>>();
+                write!(f, "unrecognized glyphs at columns {}", columns.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Reads the letters drawn in `bitmap`, such as the day 10 CRT frame.
+pub fn read(bitmap: &Bitmap) -> Result<String, OcrError> {
+    let (width, height) = (bitmap.width(), bitmap.height());
+    // The blank column after the last glyph may be cut off.
+    if height != GLYPH_HEIGHT || !(width % PITCH == 0 || (width + 1) % PITCH == 0) {
+        return Err(OcrError::Size { width, height });
+    }
+
+    let mut text = String::new();
+    let mut unrecognized = Vec::new();
+    for left in (0..width).step_by(PITCH) {
+        let glyph = FONT.iter().find(|(_, rows)| {
+            rows.iter().enumerate().all(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .all(|(x, c)| bitmap.get(left + x, y) == Some(c == '#'))
+            })
+        });
+        match glyph {
+            Some(&(letter, _)) => text.push(letter),
+            None => unrecognized.push(left),
+        }
+    }
+
+    if unrecognized.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::Unrecognized(unrecognized))
+    }
+}
+
+/// The letters in the font.
+pub fn letters() -> impl Iterator<Item = char> {
+    FONT.iter().map(|&(letter, _)| letter)
+}
+
+/// Draws `text` the way [`read`] expects it, each glyph followed by a blank
+/// column, or `None` if it's empty or has a letter that isn't in the font.
+pub fn draw(text: &str) -> Option<Bitmap> {
+    let glyphs = text
+        .chars()
+        .map(|c| {
+            FONT.iter()
+                .find(|&&(letter, _)| letter == c)
+                .map(|(_, rows)| rows)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if glyphs.is_empty() {
+        return None;
+    }
+
+    let mut pixels = Vec::with_capacity(glyphs.len() * PITCH * GLYPH_HEIGHT);
+    for y in 0..GLYPH_HEIGHT {
+        for rows in &glyphs {
+            pixels.extend(rows[y].chars().map(|c| c == '#'));
+            pixels.push(false);
+        }
+    }
+    Some(Bitmap::new(glyphs.len() * PITCH, pixels))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bitmap(rows: [&str; GLYPH_HEIGHT]) -> Bitmap {
+        let width = rows[0].len();
+        let pixels = rows.iter().flat_map(|row| row.chars().map(|c| c == '#'));
+        Bitmap::new(width, pixels.collect())
+    }
+
+    #[test]
+    fn test_read() {
+        let frame = bitmap([
+            "#..#.####.#....#.....##..",
+            "#..#.#....#....#....#..#.",
+            "####.###..#....#....#..#.",
+            "#..#.#....#....#....#..#.",
+            "#..#.#....#....#....#..#.",
+            "#..#.####.####.####..##..",
+        ]);
+
+        assert_eq!(read(&frame), Ok("HELLO".to_string()));
+        assert_eq!(draw("HELLO"), Some(frame));
+    }
+
+    #[test]
+    fn test_draw() {
+        let text = letters().collect::<String>();
+
+        assert_eq!(read(&draw(&text).unwrap()), Ok(text));
+        assert_eq!(draw("HELLO WORLD"), None);
+        assert_eq!(draw(""), None);
+    }
+
+    #[test]
+    fn test_unrecognized() {
+        let frame = bitmap([
+            "####.#..#.####",
+            "...#.#..#.#...",
+            "..#..####.#...",
+            ".#...#..#.###.",
+            "#....#..#.#...",
+            "####.#..#.####",
+        ]);
+        let error = read(&frame).unwrap_err();
+
+        assert_eq!(error, OcrError::Unrecognized(vec![10]));
+        assert_eq!(error.to_string(), "unrecognized glyphs at columns 10");
+    }
+
+    #[test]
+    fn test_size() {
+        let frame = Bitmap::new(3, vec![false; 18]);
+
+        assert_eq!(
+            read(&frame),
+            Err(OcrError::Size {
+                width: 3,
+                height: 6
+            })
+        );
+    }
+}
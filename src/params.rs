@@ -0,0 +1,132 @@
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// A day's tunable settings, such as sizes that differ between the example
+/// and the real input. The defaults are the real puzzle's. Implementors should
+/// use `#[serde(default, deny_unknown_fields)]` so that overrides may name any
+/// subset of the fields and misspelt ones are caught.
+pub trait Params: Default + Clone + fmt::Debug + Serialize + DeserializeOwned {
+    /// Rejects values the day can't work with.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// The parameters of a day that has none.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+impl Params for NoParams {}
+
+/// Overrides for some of a day's [`Params`], as given with `--param` or in a
+/// `[params.YYYY.NN]` table of `aoc.toml`, before they're checked against the
+/// day's type.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Overrides(toml::Table);
+
+impl Overrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn set(&mut self, key: &str, value: toml::Value) {
+        self.0.insert(key.to_string(), value);
+    }
+
+    /// Sets a `key=value` argument. The value is read as TOML, or taken as a
+    /// string if it isn't valid TOML.
+    pub fn set_arg(&mut self, arg: &str) -> Result<(), String> {
+        let (key, value) = arg
+            .split_once('=')
+            .ok_or_else(|| format!("expected key=value, found {:?}", arg))?;
+        let value = toml::from_str::<toml::Table>(&format!("value = {}", value))
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(value.to_string()));
+
+        self.set(key.trim(), value);
+        Ok(())
+    }
+
+    /// The default parameters with these overrides applied.
+    pub fn resolve<P: Params>(&self) -> Result<P, String> {
+        let params = if self.is_empty() {
+            P::default()
+        } else {
+            toml::Value::Table(self.0.clone())
+                .try_into::<P>()
+                .map_err(|e| {
+                    e.to_string()
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })?
+        };
+        params.validate()?;
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Screen {
+        width: usize,
+        name: String,
+    }
+
+    impl Params for Screen {
+        fn validate(&self) -> Result<(), String> {
+            match self.width {
+                0 => Err("width must be positive".to_string()),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_resolve() {
+        let mut overrides = Overrides::new();
+        overrides.set_arg("width=40").unwrap();
+        overrides.set_arg("name=crt").unwrap();
+
+        assert_eq!(
+            overrides.resolve::<Screen>(),
+            Ok(Screen {
+                width: 40,
+                name: "crt".to_string()
+            })
+        );
+        assert_eq!(Overrides::new().resolve::<NoParams>(), Ok(NoParams {}));
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut overrides = Overrides::new();
+        assert!(overrides.set_arg("width").is_err());
+
+        overrides.set_arg("widht=40").unwrap();
+        assert!(overrides
+            .resolve::<Screen>()
+            .unwrap_err()
+            .contains("unknown field `widht`"));
+
+        let mut overrides = Overrides::new();
+        overrides.set_arg("width=0").unwrap();
+        assert_eq!(
+            overrides.resolve::<Screen>(),
+            Err("width must be positive".to_string())
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{end_of, ParseError, Source};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Piece<'p> {
+    Literal(&'p str),
+    Field(&'p str),
+}
+
+/// A line template such as `"move {qty} from {src} to {dst}"`. Text outside
+/// braces must match exactly; each `{field}` captures everything up to the
+/// next literal, or to the end of the line if it comes last.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern<'p> {
+    pieces: Vec<Piece<'p>>,
+}
+
+impl<'p> Pattern<'p> {
+    /// Panics if the template is malformed: templates are written in code, so
+    /// that's a bug rather than bad input.
+    pub fn new(template: &'p str) -> Self {
+        let mut pieces = Vec::new();
+        let mut rest = template;
+
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                pieces.push(Piece::Literal(&rest[..start]));
+            }
+            let end = rest[start..]
+                .find('}')
+                .unwrap_or_else(|| panic!("unclosed field in pattern {:?}", template));
+            let name = &rest[start + 1..start + end];
+            assert!(!name.is_empty(), "unnamed field in pattern {:?}", template);
+            if let Some(Piece::Field(previous)) = pieces.last() {
+                panic!(
+                    "fields {:?} and {:?} need a literal between them in pattern {:?}",
+                    previous, name, template
+                );
+            }
+            pieces.push(Piece::Field(name));
+            rest = &rest[start + end + 1..];
+        }
+        if !rest.is_empty() {
+            pieces.push(Piece::Literal(rest));
+        }
+
+        Self { pieces }
+    }
+
+    /// Matches `s`, which must be a slice of `source`'s input so that errors
+    /// point at the right place.
+    pub fn captures<'s>(&self, source: &Source<'s>, s: &'s str) -> Result<Captures<'s>, ParseError>
+    where
+        'p: 's,
+    {
+        let mut fields = Vec::new();
+        let mut rest = s;
+
+        let mut pieces = self.pieces.iter().peekable();
+        while let Some(piece) = pieces.next() {
+            match *piece {
+                Piece::Literal(literal) => rest = source.strip_prefix(rest, literal)?,
+                Piece::Field(name) => match pieces.peek() {
+                    Some(Piece::Literal(literal)) => {
+                        let (value, _) = source.split_once(rest, literal)?;
+                        fields.push((name, value));
+                        rest = &rest[value.len()..];
+                    }
+                    _ => {
+                        fields.push((name, rest));
+                        rest = end_of(rest);
+                    }
+                },
+            }
+        }
+
+        if !rest.is_empty() {
+            return Err(source.error(rest, "unexpected text"));
+        }
+
+        Ok(Captures {
+            source: *source,
+            fields,
+        })
+    }
+}
+
+/// The text captured for each field of a [`Pattern`].
+#[derive(Clone, Debug)]
+pub struct Captures<'s> {
+    source: Source<'s>,
+    fields: Vec<(&'s str, &'s str)>,
+}
+
+impl<'s> Captures<'s> {
+    /// Panics if the pattern has no such field.
+    pub fn get(&self, name: &str) -> &'s str {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| *value)
+            .unwrap_or_else(|| panic!("pattern has no field {:?}", name))
+    }
+
+    pub fn parse<T>(&self, name: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.source.parse(self.get(name))
+    }
+
+    /// An error pointing at the text captured for `name`.
+    pub fn error(&self, name: &str, message: impl fmt::Display) -> ParseError {
+        self.source.error(self.get(name), message)
+    }
+}
+
+/// Implements `FromStr` for a struct by matching a [`Pattern`].
+///
+/// With a list of fields, each is parsed from the capture of the same name:
+///
+/// ```
+/// # use aoc_2021::from_pattern;
+/// struct Move { qty: usize, src: usize, dst: usize }
+/// from_pattern!(5, Move, "move {qty} from {src} to {dst}", { qty, src, dst });
+///
+/// let m: Move = "move 3 from 1 to 2".parse().unwrap();
+/// assert_eq!((m.qty, m.src, m.dst), (3, 1, 2));
+/// ```
+///
+/// Otherwise the value is built by an expression over the captures, in which
+/// `?` may be used.
+#[macro_export]
+macro_rules! from_pattern {
+    ($day:expr, $type:ty, $template:literal, { $($field:ident),* $(,)? }) => {
+        $crate::from_pattern!($day, $type, $template, |captures| Self {
+            $($field: captures.parse(stringify!($field))?,)*
+        });
+    };
+    ($day:expr, $type:ty, $template:literal, |$captures:ident| $body:expr) => {
+        impl ::std::str::FromStr for $type {
+            type Err = $crate::error::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let source = $crate::error::Source::new($day, s);
+                let $captures = $crate::pattern::Pattern::new($template).captures(&source, s)?;
+                Ok($body)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Move {
+        qty: usize,
+        src: usize,
+        dst: usize,
+    }
+
+    from_pattern!(5, Move, "move {qty} from {src} to {dst}", { qty, src, dst });
+
+    #[derive(Debug, PartialEq)]
+    struct Range(u32, u32);
+
+    from_pattern!(4, Range, "{start}-{end}", |c| Range(
+        c.parse("start")?,
+        c.parse("end")?
+    ));
+
+    #[test]
+    fn test_captures() {
+        let input = "Sensor at x=2, y=-18: beacon";
+        let source = Source::new(15, input);
+        let pattern = Pattern::new("Sensor at x={x}, y={y}: {rest}");
+        let captures = pattern.captures(&source, input).unwrap();
+
+        assert_eq!(captures.get("x"), "2");
+        assert_eq!(captures.parse::<i64>("y"), Ok(-18));
+        assert_eq!(captures.get("rest"), "beacon");
+    }
+
+    #[test]
+    fn test_from_pattern() {
+        assert_eq!(
+            "move 3 from 1 to 2".parse::<Move>(),
+            Ok(Move {
+                qty: 3,
+                src: 1,
+                dst: 2
+            })
+        );
+        assert_eq!("2-40".parse::<Range>(), Ok(Range(2, 40)));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = "move 3 from 1 into 2".parse::<Move>().unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (21, ""));
+        assert_eq!(error.message, "expected \" to \"");
+
+        let error = "move x from 1 to 2".parse::<Move>().unwrap_err();
+        assert_eq!((error.day, error.column, error.text.as_str()), (5, 6, "x"));
+        assert_eq!(
+            error.message,
+            "expected usize: invalid digit found in string"
+        );
+
+        let error = "shift 3 from 1 to 2".parse::<Move>().unwrap_err();
+        assert_eq!(error.column, 1);
+        assert_eq!(error.message, "expected \"move \"");
+
+        let error = "2-4!".parse::<Range>().unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (3, "4!"));
+    }
+
+    #[test]
+    fn test_trailing_literal() {
+        let input = "Monkey 3: extra";
+        let source = Source::new(11, input);
+        let error = Pattern::new("Monkey {id}:")
+            .captures(&source, input)
+            .unwrap_err();
+
+        assert_eq!((error.column, error.text.as_str()), (10, " extra"));
+        assert_eq!(error.message, "unexpected text");
+    }
+
+    #[test]
+    #[should_panic(expected = "need a literal between them")]
+    fn test_adjacent_fields() {
+        Pattern::new("{a}{b}");
+    }
+}
@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+
+use crate::runner::Event;
+use crate::solution::{Solver, Stage};
+
+/// Live progress for [`runner::run_all`](crate::runner::run_all): one bar per
+/// day, stepping through parsing and each part.
+pub struct RunProgress {
+    multi: MultiProgress,
+    bars: Vec<((u16, u8), ProgressBar)>,
+}
+
+impl RunProgress {
+    /// Draws to stderr; indicatif hides the bars when it isn't a terminal.
+    pub fn new(solvers: &[&dyn Solver], parts: usize) -> Self {
+        Self::with_draw_target(solvers, parts, ProgressDrawTarget::stderr())
+    }
+
+    pub fn with_draw_target(
+        solvers: &[&dyn Solver],
+        parts: usize,
+        target: ProgressDrawTarget,
+    ) -> Self {
+        let multi = MultiProgress::with_draw_target(target);
+        let style = ProgressStyle::with_template(
+            "{spinner:.green} Day {prefix}  [{bar:10.cyan/blue}] {msg} ({elapsed})",
+        )
+        .expect("template is valid")
+        .progress_chars("=> ");
+
+        let bars = solvers
+            .iter()
+            .map(|solver| {
+                let bar = multi.add(ProgressBar::new(parts as u64 + 1));
+                bar.set_style(style.clone());
+                bar.set_prefix(format!("{:02}", solver.day()));
+                bar.set_message("waiting");
+                ((solver.year(), solver.day()), bar)
+            })
+            .collect();
+
+        Self { multi, bars }
+    }
+
+    fn bar(&self, year: u16, day: u8) -> Option<&ProgressBar> {
+        self.bars
+            .iter()
+            .find(|(key, _)| *key == (year, day))
+            .map(|(_, bar)| bar)
+    }
+
+    pub fn handle(&self, event: Event) {
+        match event {
+            Event::Stage { year, day, stage } => {
+                let Some(bar) = self.bar(year, day) else {
+                    return;
+                };
+                match stage {
+                    Stage::Parse => {
+                        bar.reset_elapsed();
+                        bar.enable_steady_tick(Duration::from_millis(100));
+                        bar.set_message("parsing");
+                    }
+                    Stage::Part(part) => {
+                        bar.inc(1);
+                        bar.set_message(format!("part {}", part));
+                    }
+                }
+            }
+            Event::Finished { year, day, result } => {
+                let Some(bar) = self.bar(year, day) else {
+                    return;
+                };
+                match result {
+                    Ok(report) => {
+                        bar.set_position(bar.length().unwrap_or(0));
+                        bar.finish_with_message(format!("done in {:.2?}", report.total()));
+                    }
+                    Err(_) => bar.abandon_with_message("failed"),
+                }
+            }
+        }
+    }
+
+    /// Removes the bars so the summary can be printed in their place.
+    pub fn clear(&self) {
+        let _ = self.multi.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runner;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_handle() {
+        let solvers = [crate::find(2022, 1).unwrap(), crate::find(2022, 4).unwrap()];
+        let progress = RunProgress::with_draw_target(&solvers, 2, ProgressDrawTarget::hidden());
+
+        progress.handle(Event::Stage {
+            year: 2022,
+            day: 4,
+            stage: Stage::Part(Part::One),
+        });
+        let bar = progress.bar(2022, 4).unwrap();
+        assert_eq!((bar.position(), bar.message()), (1, "part 1".to_string()));
+
+        let result = runner::run(solvers[0], "1\n2", &Part::ALL);
+        progress.handle(Event::Finished {
+            year: 2022,
+            day: 1,
+            result: &result,
+        });
+        let bar = progress.bar(2022, 1).unwrap();
+        assert_eq!(bar.position(), 3);
+        assert!(bar.is_finished());
+        assert!(bar.message().starts_with("done in "));
+
+        let result = runner::run(solvers[1], "x", &Part::ALL);
+        progress.handle(Event::Finished {
+            year: 2022,
+            day: 4,
+            result: &result,
+        });
+        assert_eq!(progress.bar(2022, 4).unwrap().message(), "failed");
+    }
+}
@@ -0,0 +1,389 @@
+use crate::answers::Expected;
+use crate::examples::Example;
+
+/// How wide `render` wraps paragraphs.
+const WIDTH: usize = 80;
+
+/// A piece of a page, as split up by [`tokens`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open(String),
+    Close(String),
+    Text(&'a str),
+}
+
+/// Splits `html` into tags and the text between them. Tag names are
+/// lowercased and attributes dropped; comments are skipped. This is only
+/// meant for the site's own, well-formed pages.
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(rest));
+            return tokens;
+        };
+
+        let tag = &rest[1..end];
+        let (close, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        tokens.push(match close {
+            true => Token::Close(name),
+            false => Token::Open(name),
+        });
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+    tokens
+}
+
+/// Replaces the character references the site uses with their characters.
+fn decode(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                entity => {
+                    let number = entity.strip_prefix('#')?;
+                    let code = match number.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => number.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end + 1))
+        });
+        match decoded {
+            Some((c, len)) => {
+                output.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// The tokens of each of the page's puzzle descriptions: one for part 1 and,
+/// once it's unlocked, one for part 2. A page without any `<article>` is
+/// taken to be a single description.
+fn articles(html: &str) -> Vec<Vec<Token<'_>>> {
+    let mut articles = Vec::new();
+    let mut current = None;
+
+    for token in tokens(html) {
+        match (&token, &mut current) {
+            (Token::Open(name), None) if name == "article" => current = Some(Vec::new()),
+            (Token::Close(name), Some(_)) if name == "article" => {
+                articles.extend(current.take());
+            }
+            (_, Some(article)) => article.push(token),
+            (_, None) => {}
+        }
+    }
+
+    if articles.is_empty() {
+        articles.push(tokens(html));
+    }
+    articles
+}
+
+/// One block of rendered text.
+enum Block {
+    /// Text to be wrapped, with the prefix for its first line.
+    Paragraph(String, &'static str),
+    Preformatted(String),
+}
+
+/// The puzzle descriptions on `html` as plain text for a terminal, with
+/// paragraphs wrapped and examples indented.
+pub fn render(html: &str) -> String {
+    let mut blocks = Vec::new();
+
+    for article in articles(html) {
+        let mut pre = None;
+        for token in article {
+            match token {
+                Token::Open(name) if name == "pre" => pre = Some(String::new()),
+                Token::Close(name) if name == "pre" => {
+                    blocks.extend(pre.take().map(Block::Preformatted));
+                }
+                Token::Text(text) if pre.is_some() => {
+                    pre.as_mut().unwrap().push_str(&decode(text));
+                }
+                Token::Open(name) if matches!(name.as_str(), "p" | "h2") => {
+                    blocks.push(Block::Paragraph(String::new(), ""));
+                }
+                Token::Open(name) if name == "li" => {
+                    blocks.push(Block::Paragraph(String::new(), "  - "));
+                }
+                Token::Text(text) => {
+                    if !matches!(blocks.last(), Some(Block::Paragraph(..))) {
+                        if text.trim().is_empty() {
+                            continue;
+                        }
+                        blocks.push(Block::Paragraph(String::new(), ""));
+                    }
+                    if let Some(Block::Paragraph(paragraph, _)) = blocks.last_mut() {
+                        paragraph.push_str(&decode(text));
+                    }
+                }
+                Token::Close(name) if matches!(name.as_str(), "p" | "h2" | "li" | "ul") => {
+                    // Text after a block belongs to a new paragraph.
+                    blocks.push(Block::Paragraph(String::new(), ""));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut output = String::new();
+    let mut last_prefix = None;
+    for block in blocks {
+        match block {
+            Block::Paragraph(text, prefix) => {
+                let words = text.split_whitespace().collect::<Vec<_>>();
+                if words.is_empty() {
+                    continue;
+                }
+                // List items follow each other without a blank line.
+                let next_item = prefix == "  - " && last_prefix == Some(prefix);
+                if !output.is_empty() && !next_item {
+                    output.push('\n');
+                }
+                output.push_str(&wrap(&words, prefix));
+                last_prefix = Some(prefix);
+            }
+            Block::Preformatted(text) => {
+                if !output.is_empty() {
+                    output.push('\n');
+                }
+                for line in text.trim_end_matches('\n').lines() {
+                    output.push_str(format!("    {}", line).trim_end());
+                    output.push('\n');
+                }
+                last_prefix = None;
+            }
+        }
+    }
+    output
+}
+
+/// Joins `words` into lines of at most [`WIDTH`] columns where possible. The
+/// first line starts with `prefix` and the rest are indented to match.
+fn wrap(words: &[&str], prefix: &str) -> String {
+    let indent = " ".repeat(prefix.chars().count());
+    let mut output = String::new();
+    let mut line = prefix.to_string();
+
+    for word in words {
+        let len = line.chars().count();
+        if len > indent.len() && len + 1 + word.chars().count() > WIDTH {
+            output.push_str(&line);
+            output.push('\n');
+            line = indent.clone();
+        } else if len > indent.len() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    output.push_str(&line);
+    output.push('\n');
+    output
+}
+
+/// The examples in the puzzle descriptions on `html`: the contents of each
+/// `<pre><code>` block. The answer to each part is guessed to be the last
+/// emphasized code in that part's description, such as
+/// `<code><em>24000</em></code>`, and is given to the example that came
+/// before it.
+pub fn extract(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+
+    for (part, article) in articles(html).into_iter().enumerate().take(2) {
+        let mut pre = None;
+        let mut code = 0usize;
+        let mut em = None;
+        let mut answer = None;
+
+        for token in article {
+            match token {
+                Token::Open(name) if name == "pre" => pre = Some(String::new()),
+                Token::Close(name) if name == "pre" => {
+                    let input = pre.take().unwrap_or_default();
+                    if !input.trim().is_empty() {
+                        examples.push(Example {
+                            input,
+                            ..Example::default()
+                        });
+                    }
+                }
+                Token::Text(text) if pre.is_some() => {
+                    pre.as_mut().unwrap().push_str(&decode(text));
+                }
+                Token::Open(name) if name == "code" => {
+                    code += 1;
+                    // Answers are written both ways round: <em><code>…
+                    if let Some((_, in_code)) = em.as_mut() {
+                        *in_code = true;
+                    }
+                }
+                Token::Close(name) if name == "code" => code = code.saturating_sub(1),
+                Token::Open(name) if name == "em" => em = Some((String::new(), code > 0)),
+                Token::Close(name) if name == "em" => {
+                    let (text, in_code) = em.take().unwrap_or_default();
+                    if in_code && !text.trim().is_empty() && !examples.is_empty() {
+                        answer = Some((examples.len() - 1, text.trim().to_string()));
+                    }
+                }
+                Token::Text(text) => {
+                    if let Some((em, _)) = em.as_mut() {
+                        em.push_str(&decode(text));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some((index, answer)) = answer {
+            let expected = match answer.parse() {
+                Ok(n) => Expected::Integer(n),
+                Err(_) => Expected::Text(answer),
+            };
+            match part {
+                0 => examples[index].part1 = Some(expected),
+                _ => examples[index].part2 = Some(expected),
+            }
+        }
+    }
+
+    examples
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2022</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <em>Calories</em> contained by the various meals, snacks, rations, etc. that they've brought with them, one item per line.</p>
+<p>For example, suppose the Elves finish writing their items' Calories and end up with the following list:</p>
+<pre><code>1000
+2000
+
+4000
+</code></pre>
+<ul>
+<li>The first Elf is carrying food with <code>1000</code> and <code>2000</code> Calories, a total of <code><em>3000</em></code> Calories.</li>
+<li>The second Elf is carrying one food item with <code>4000</code> Calories.</li>
+</ul>
+<p>In the example above, this is <em><code>4000</code></em> (carried by the second Elf).</p>
+<!-- <p>Not <code><em>1</em></code>.</p> -->
+</article>
+<p>Your puzzle answer was <code>71502</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>In the example above, the top three Elves are carrying a total of <code><em>7000</em></code> Calories &amp; more &lt;3.</p>
+</article>
+</main>
+</body>
+</html>
+"#;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("a &lt;b&gt; &amp; &#39;c&#x27;"), "a <b> & 'c'");
+        assert_eq!(decode("fish &chips; & &bogus;"), "fish &chips; & &bogus;");
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(PAGE),
+            "--- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of Calories contained by the
+various meals, snacks, rations, etc. that they've brought with them, one item
+per line.
+
+For example, suppose the Elves finish writing their items' Calories and end up
+with the following list:
+
+    1000
+    2000
+
+    4000
+
+  - The first Elf is carrying food with 1000 and 2000 Calories, a total of 3000
+    Calories.
+  - The second Elf is carrying one food item with 4000 Calories.
+
+In the example above, this is 4000 (carried by the second Elf).
+
+--- Part Two ---
+
+In the example above, the top three Elves are carrying a total of 7000 Calories
+& more <3.
+"
+        );
+    }
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            extract(PAGE),
+            vec![Example {
+                input: "1000\n2000\n\n4000\n".to_string(),
+                part1: Some(Expected::Integer(4000)),
+                part2: Some(Expected::Integer(7000)),
+                ..Example::default()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_extract_before_part_two() {
+        let page = PAGE.split("<p>Your puzzle answer").next().unwrap();
+        let examples = extract(page);
+
+        assert_eq!(examples[0].part1, Some(Expected::Integer(4000)));
+        assert_eq!(examples[0].part2, None);
+        assert!(extract("<p>No examples today.</p>").is_empty());
+    }
+}
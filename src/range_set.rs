@@ -0,0 +1,255 @@
+use std::fmt;
+
+/// An inclusive range of integers, like the `2-4` in an assignment pair.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        assert!(start <= end, "interval {}-{} is backwards", start, end);
+        Self { start, end }
+    }
+
+    pub fn point(x: i64) -> Self {
+        Self::new(x, x)
+    }
+
+    /// The number of integers in the interval, saturating for the full range
+    /// of `i64`. Intervals are never empty.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        self.end.abs_diff(self.start).saturating_add(1)
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        self.overlaps(other)
+            .then(|| Interval::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint intervals. Touching
+/// intervals are merged, so `1-2` and `3-4` become `1-4`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals
+            .iter()
+            .map(Interval::len)
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // The first interval that could touch the new one, and the first past it.
+        let from = self
+            .intervals
+            .partition_point(|i| i.end.saturating_add(1) < interval.start);
+        let to = self
+            .intervals
+            .partition_point(|i| i.start.saturating_sub(1) <= interval.end);
+
+        let merged = self.intervals[from..to]
+            .iter()
+            .fold(interval, |merged, i| Interval {
+                start: merged.start.min(i.start),
+                end: merged.end.max(i.end),
+            });
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval) {
+        let mut remaining = Vec::with_capacity(self.intervals.len() + 1);
+        for &i in &self.intervals {
+            if !i.overlaps(&interval) {
+                remaining.push(i);
+                continue;
+            }
+            if i.start < interval.start {
+                remaining.push(Interval::new(i.start, interval.start - 1));
+            }
+            if interval.end < i.end {
+                remaining.push(Interval::new(interval.end + 1, i.end));
+            }
+        }
+        self.intervals = remaining;
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.find(x).is_some()
+    }
+
+    pub fn contains_interval(&self, interval: &Interval) -> bool {
+        self.find(interval.start)
+            .is_some_and(|i| i.contains_interval(interval))
+    }
+
+    fn find(&self, x: i64) -> Option<&Interval> {
+        let i = self.intervals.partition_point(|i| i.end < x);
+        self.intervals.get(i).filter(|i| i.contains(x))
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut set = self.clone();
+        set.extend(other.intervals.iter().copied());
+        set
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let (mut a, mut b) = (self.intervals.iter(), other.intervals.iter());
+        let (mut x, mut y) = (a.next(), b.next());
+        let mut intervals = Vec::new();
+
+        while let (Some(i), Some(j)) = (x, y) {
+            intervals.extend(i.intersection(j));
+            if i.end < j.end {
+                x = a.next();
+            } else {
+                y = b.next();
+            }
+        }
+
+        RangeSet { intervals }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut set = self.clone();
+        for &interval in &other.intervals {
+            set.remove(interval);
+        }
+        set
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(interval: Interval) -> Self {
+        Self {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl Extend<Interval> for RangeSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl fmt::Display for RangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (n, interval) in self.intervals.iter().enumerate() {
+            if n > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", interval)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(2, 6);
+
+        assert_eq!(a.len(), 5);
+        assert!(a.contains_interval(&Interval::new(4, 6)));
+        assert!(!a.contains_interval(&Interval::new(4, 7)));
+        assert!(a.overlaps(&Interval::new(6, 8)));
+        assert!(!a.overlaps(&Interval::new(7, 8)));
+        assert_eq!(
+            a.intersection(&Interval::new(5, 9)),
+            Some(Interval::new(5, 6))
+        );
+        assert_eq!(Interval::point(-3).len(), 1);
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let set = set(&[(10, 12), (1, 2), (3, 4), (20, 30), (11, 21)]);
+
+        assert_eq!(set.to_string(), "1-4,10-30");
+        assert_eq!(set.len(), 25);
+        assert!(set.contains(15));
+        assert!(!set.contains(5));
+        assert!(set.contains_interval(&Interval::new(10, 30)));
+        assert!(!set.contains_interval(&Interval::new(4, 10)));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+
+        assert_eq!(a.union(&b).to_string(), "0-30");
+        assert_eq!(a.intersection(&b).to_string(), "5-10,20-25");
+        assert_eq!(a.difference(&b).to_string(), "0-4,26-30");
+        assert_eq!(b.difference(&a).to_string(), "11-19");
+        assert!(a.intersection(&RangeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_extremes() {
+        let mut set = RangeSet::from(Interval::new(i64::MIN, i64::MAX));
+        set.remove(Interval::point(0));
+
+        assert_eq!(set.len(), u64::MAX);
+        assert!(!set.contains(0));
+    }
+}
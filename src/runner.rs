@@ -0,0 +1,597 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::answer::Answer;
+use crate::answers::{Expected, KnownAnswers};
+use crate::config::{Config, DEFAULT_PROFILE};
+use crate::error::{DayError, ReadError};
+use crate::params::Overrides;
+use crate::solution::{Part, Solver, Stage};
+use crate::table::align;
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's committed input under `input/`.
+    Default,
+    /// A named input profile, from `input/<year>/NN/<profile>.txt`.
+    Profile(String),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: Option<&Path>) -> Self {
+        match arg {
+            None => Self::Default,
+            Some(path) if path == Path::new("-") => Self::Stdin,
+            Some(path) => Self::File(path.to_path_buf()),
+        }
+    }
+
+    /// Opens the input to be read a piece at a time. Stdin can only be
+    /// opened once.
+    pub fn open(&self, config: &Config, year: u16, day: u8) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Self::Default => open_file(&config.input_path(year, day)),
+            Self::Profile(profile) => open_file(&config.profile_path(year, day, profile)),
+            Self::File(path) => open_file(path),
+            Self::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    pub fn read(&self, config: &Config, year: u16, day: u8) -> io::Result<String> {
+        match self {
+            Self::Default => read_file(&config.input_path(year, day)),
+            Self::Profile(profile) => read_file(&config.profile_path(year, day, profile)),
+            Self::File(path) => read_file(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path).map_err(|e| with_path(path, e))
+}
+
+fn open_file(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    let file = fs::File::open(path).map_err(|e| with_path(path, e))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn with_path(path: &Path, e: io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+}
+
+/// The profiles that have an input for `day` of `year`: the default one
+/// first, then the rest by name.
+pub fn profiles(config: &Config, year: u16, day: u8) -> io::Result<Vec<String>> {
+    list_profiles(&config.input_dir().join(year.to_string()), day)
+}
+
+fn list_profiles(dir: &Path, day: u8) -> io::Result<Vec<String>> {
+    let mut profiles = Vec::new();
+    if dir.join(format!("{:02}.txt", day)).is_file() {
+        profiles.push(DEFAULT_PROFILE.to_string());
+    }
+
+    let day_dir = dir.join(format!("{:02}", day));
+    let entries = match fs::read_dir(&day_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(profiles),
+        Err(e) => return Err(with_path(&day_dir, e)),
+    };
+    let mut named = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| with_path(&day_dir, e))?.path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                named.push(name.to_string());
+            }
+        }
+    }
+    named.sort();
+    profiles.extend(named.into_iter().filter(|name| name != DEFAULT_PROFILE));
+    Ok(profiles)
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().map(|part| part.elapsed).sum::<Duration>()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Day {:02}: {}", self.day, self.title)?;
+        if !self.parse.is_zero() {
+            writeln!(f, "  parse  ({:.2?})", self.parse)?;
+        }
+        for part in &self.parts {
+            if part.answer.is_multiline() {
+                writeln!(f, "  part {} ({:.2?})", part.part, part.elapsed)?;
+                for line in part.answer.to_string().lines() {
+                    writeln!(f, "    {}", line)?;
+                }
+            } else {
+                writeln!(
+                    f,
+                    "  part {} ({:.2?}): {}",
+                    part.part, part.elapsed, part.answer
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses `input` once and solves each of `parts` with the default
+/// parameters, timing every step.
+pub fn run(solver: &dyn Solver, input: &str, parts: &[Part]) -> Result<Report, DayError> {
+    run_observed(solver, input, parts, &Overrides::new(), &mut |_| {})
+}
+
+/// Like [`run`] with the given parameters, calling `on_stage` as each step
+/// starts.
+pub fn run_observed(
+    solver: &dyn Solver,
+    input: &str,
+    parts: &[Part],
+    params: &Overrides,
+    on_stage: &mut dyn FnMut(Stage),
+) -> Result<Report, DayError> {
+    on_stage(Stage::Parse);
+    let start = Instant::now();
+    let parsed = solver.parse_with(input, params)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            on_stage(Stage::Part(part));
+            let start = Instant::now();
+            let answer = parsed.solve(part)?;
+            Ok(PartReport {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            })
+        })
+        .collect::<Result<_, DayError>>()?;
+
+    Ok(Report {
+        year: solver.year(),
+        day: solver.day(),
+        title: solver.title(),
+        parse,
+        parts,
+    })
+}
+
+/// Solves each of `parts` straight from `source`, which is opened again for
+/// every part. Reading is timed as part of solving, so `parse` is zero.
+pub fn run_stream(
+    solver: &dyn Solver,
+    config: &Config,
+    source: &InputSource,
+    parts: &[Part],
+    params: &Overrides,
+) -> Result<Report, ReadError> {
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let mut reader = source.open(config, solver.year(), solver.day())?;
+            let answer = solver.solve_reader(&mut reader, part, params)?;
+            Ok(PartReport {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            })
+        })
+        .collect::<Result<_, ReadError>>()?;
+
+    Ok(Report {
+        year: solver.year(),
+        day: solver.day(),
+        title: solver.title(),
+        parse: Duration::ZERO,
+        parts,
+    })
+}
+
+/// A day to solve with [`run_all`].
+pub struct Job<'a> {
+    pub solver: &'a dyn Solver,
+    pub input: String,
+    pub params: Overrides,
+}
+
+impl<'a> Job<'a> {
+    /// A job with the default parameters.
+    pub fn new(solver: &'a dyn Solver, input: impl Into<String>) -> Self {
+        Self {
+            solver,
+            input: input.into(),
+            params: Overrides::new(),
+        }
+    }
+}
+
+/// Progress of [`run_all`], reported from the worker threads.
+#[derive(Debug)]
+pub enum Event<'a> {
+    Stage {
+        year: u16,
+        day: u8,
+        stage: Stage,
+    },
+    Finished {
+        year: u16,
+        day: u8,
+        result: &'a Result<Report, DayError>,
+    },
+}
+
+/// Runs each job on a pool of `threads` workers. Results are returned in the
+/// order of `jobs`, whichever finishes first.
+pub fn run_all(
+    jobs: &[Job],
+    parts: &[Part],
+    threads: usize,
+    on_event: &(dyn Fn(Event) + Sync),
+) -> Vec<Result<Report, DayError>> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..jobs.len()).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(i) else {
+                    break;
+                };
+
+                let (year, day) = (job.solver.year(), job.solver.day());
+                let result =
+                    run_observed(job.solver, &job.input, parts, &job.params, &mut |stage| {
+                        on_event(Event::Stage { year, day, stage })
+                    });
+                on_event(Event::Finished {
+                    year,
+                    day,
+                    result: &result,
+                });
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every job is run"))
+        .collect()
+}
+
+/// A table of every report's answers and timings. Multiline answers don't fit
+/// in a cell, so they are printed after the table.
+pub fn summary(reports: &[Report]) -> String {
+    let mut rows = vec![["Day", "Title", "Part 1", "Part 2", "Time"].map(String::from)];
+    let mut pictures = Vec::new();
+
+    for report in reports {
+        let mut row = [
+            format!("{:02}", report.day),
+            report.title.to_string(),
+            "-".to_string(),
+            "-".to_string(),
+            format!("{:.2?}", report.total()),
+        ];
+        for part in &report.parts {
+            let cell = &mut row[part.part.number() as usize + 1];
+            if part.answer.is_multiline() {
+                *cell = "(see below)".to_string();
+                pictures.push((report.day, part.part, part.answer.to_string()));
+            } else {
+                *cell = part.answer.to_string();
+            }
+        }
+        rows.push(row);
+    }
+
+    let mut table = align(&rows);
+    let total = reports.iter().map(Report::total).sum::<Duration>();
+    table.push_str(&format!("Total ({:.2?})\n", total));
+
+    for (day, part, picture) in pictures {
+        table.push_str(&format!("\nDay {:02} part {}:\n", day, part));
+        for line in picture.lines() {
+            table.push_str(&format!("  {}\n", line));
+        }
+    }
+
+    table
+}
+
+/// A day solved on one of its input profiles.
+#[derive(Debug)]
+pub struct ProfileReport {
+    pub profile: String,
+    pub report: Report,
+}
+
+/// How an answer compares with the one recorded for its input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Agrees,
+    Disagrees(Expected),
+    Unrecorded,
+}
+
+impl Check {
+    pub fn new(expected: Option<&Expected>, answer: &Answer) -> Self {
+        match expected {
+            Some(expected) if expected.matches(answer) => Self::Agrees,
+            Some(expected) => Self::Disagrees(expected.clone()),
+            None => Self::Unrecorded,
+        }
+    }
+}
+
+/// A table of each profile's answers checked against those recorded in
+/// `known`, and the number of answers that disagree with them.
+pub fn cross_check(reports: &[ProfileReport], known: &KnownAnswers) -> (String, usize) {
+    let mut rows = vec![["Day", "Profile", "Part 1", "Part 2"].map(String::from)];
+    let mut disagreements = 0;
+
+    for ProfileReport { profile, report } in reports {
+        let answers = known.profile(report.year, report.day, profile);
+        let mut row = [
+            format!("{:02}", report.day),
+            profile.clone(),
+            "-".to_string(),
+            "-".to_string(),
+        ];
+        for part in &report.parts {
+            let expected = answers.and_then(|answers| answers.get(part.part));
+            let answer = match part.answer.is_multiline() {
+                true => "(picture)".to_string(),
+                false => part.answer.to_string(),
+            };
+            row[part.part.number() as usize + 1] = match Check::new(expected, &part.answer) {
+                Check::Agrees => answer,
+                Check::Disagrees(expected) if expected.to_string().contains('\n') => {
+                    disagreements += 1;
+                    format!("{} (expected another picture)", answer)
+                }
+                Check::Disagrees(expected) => {
+                    disagreements += 1;
+                    format!("{} (expected {})", answer, expected)
+                }
+                Check::Unrecorded => format!("{} (unrecorded)", answer),
+            };
+        }
+        rows.push(row);
+    }
+
+    (align(&rows), disagreements)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::ParseError;
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_input_source_from_arg() {
+        assert_eq!(InputSource::from_arg(None), InputSource::Default);
+        assert_eq!(
+            InputSource::from_arg(Some(Path::new("-"))),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::from_arg(Some(Path::new("in.txt"))),
+            InputSource::File(PathBuf::from("in.txt"))
+        );
+    }
+
+    #[test]
+    fn test_profiles() {
+        let dir = TempDir::new("profiles");
+        dir.write("01.txt", "1\n");
+        dir.write("01/carol.txt", "3\n");
+        dir.write("01/bob.txt", "2\n");
+        dir.write("01/notes.md", "not an input\n");
+        dir.write("02/alice.txt", "1\n");
+
+        assert_eq!(
+            list_profiles(dir.path(), 1).unwrap(),
+            vec![DEFAULT_PROFILE, "bob", "carol"]
+        );
+        assert_eq!(list_profiles(dir.path(), 2).unwrap(), vec!["alice"]);
+        assert!(list_profiles(dir.path(), 3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let solver = crate::find(2022, 1).unwrap();
+        let known = KnownAnswers::parse(
+            "[2022.01]\npart1 = 4\npart2 = 7\n\n[2022.01.profiles.bob]\npart1 = 5\n",
+        )
+        .unwrap();
+        let reports = [
+            (DEFAULT_PROFILE, "1\n2\n\n4"),
+            ("bob", "1\n\n4"),
+            ("carol", "9"),
+        ]
+        .map(|(profile, input)| ProfileReport {
+            profile: profile.to_string(),
+            report: run(solver, input, &Part::ALL).unwrap(),
+        });
+
+        let (table, disagreements) = cross_check(&reports, &known);
+
+        assert_eq!(disagreements, 1);
+        assert_eq!(
+            table,
+            "Day  Profile  Part 1          Part 2
+---  -------  --------------  --------------
+01   default  4               7
+01   bob      4 (expected 5)  5 (unrecorded)
+01   carol    9 (unrecorded)  9 (unrecorded)
+"
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let solver = crate::find(2022, 1).unwrap();
+        let report = run(solver, "1\n2\n\n4", &Part::ALL).unwrap();
+
+        assert_eq!(report.day, 1);
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!(report.parts[0].answer, "4");
+        assert_eq!(report.parts[1].answer, "7");
+    }
+
+    #[test]
+    fn test_run_error() {
+        let solver = crate::find(2022, 1).unwrap();
+        let error = run(solver, "1\n2x\n", &Part::ALL).unwrap_err();
+
+        assert!(matches!(
+            error,
+            DayError::Parse(ParseError {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_run_stream() {
+        let dir = TempDir::new("stream");
+        dir.write("09.txt", "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n");
+        let source = InputSource::File(dir.path().join("09.txt"));
+        let report = run_stream(
+            crate::find(2022, 9).unwrap(),
+            &Config::default(),
+            &source,
+            &Part::ALL,
+            &Overrides::new(),
+        )
+        .unwrap();
+
+        assert_eq!(report.parts[0].answer, "13");
+        assert_eq!(report.parts[1].answer, "1");
+        assert!(!report.to_string().contains("parse"));
+
+        let missing = InputSource::File(dir.path().join("missing.txt"));
+        let error = run_stream(
+            crate::find(2022, 9).unwrap(),
+            &Config::default(),
+            &missing,
+            &Part::ALL,
+            &Overrides::new(),
+        )
+        .unwrap_err();
+        assert!(matches!(error, ReadError::Io(_)));
+    }
+
+    #[test]
+    fn test_run_params() {
+        let solver = crate::find(2022, 6).unwrap();
+        let mut params = Overrides::new();
+        params.set_arg("packet=3").unwrap();
+        let report = run_observed(solver, "mjqjpqmgbljsp", &[Part::One], &params, &mut |_| {});
+
+        assert_eq!(report.unwrap().parts[0].answer, "3");
+
+        params.set_arg("pakcet=3").unwrap();
+        let error = run_observed(solver, "mjqjpqmgbljsp", &[Part::One], &params, &mut |_| {});
+        assert!(error
+            .unwrap_err()
+            .to_string()
+            .starts_with("day 06: invalid parameters: unknown field `pakcet`"));
+    }
+
+    #[test]
+    fn test_run_all() {
+        let jobs = vec![
+            Job::new(crate::find(2022, 1).unwrap(), "1\n2\n\n4"),
+            Job::new(crate::find(2022, 6).unwrap(), "Not valid"),
+            Job::new(crate::find(2022, 4).unwrap(), "2-4,6-8\n2-8,3-7"),
+        ];
+        let events = Mutex::new(Vec::new());
+
+        let results = run_all(&jobs, &Part::ALL, 2, &|event| {
+            if let Event::Stage { year, day, stage } = event {
+                events.lock().unwrap().push((year, day, stage));
+            }
+        });
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().parts[1].answer, "7");
+        assert!(results[1].is_err());
+        let report = results[2].as_ref().unwrap();
+        assert_eq!((report.year, report.day), (2022, 4));
+
+        let events = events.into_inner().unwrap();
+        assert_eq!(events.len(), 7);
+        assert!(events.contains(&(2022, 6, Stage::Parse)));
+        assert!(!events.contains(&(2022, 6, Stage::Part(Part::One))));
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut picture = Overrides::new();
+        picture.set_arg("letters=false").unwrap();
+        let day10 = crate::find(2022, 10).unwrap();
+        let reports = [
+            run(crate::find(2022, 1).unwrap(), "1\n2\n\n4", &Part::ALL).unwrap(),
+            run_observed(day10, "noop", &[Part::Two], &picture, &mut |_| {}).unwrap(),
+        ];
+        let summary = summary(&reports);
+        let lines = summary.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "Day  Title             Part 1  Part 2       Time");
+        assert!(lines[2].starts_with("01   Calorie Counting  4       7            "));
+        assert!(lines[3].starts_with("10   Cathode-Ray Tube  -       (see below)  "));
+        assert!(lines[4].starts_with("Total ("));
+        assert_eq!(lines[6], "Day 10 part 2:");
+        assert_eq!(lines.len(), 13);
+    }
+}
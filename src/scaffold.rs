@@ -0,0 +1,480 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+
+/// The registry of a year that has no days yet.
+const YEAR_MODULE: &str = "use crate::solution::Solver;
+
+pub static DAYS: &[&dyn Solver] = &[];
+";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidYear(u16),
+    InvalidDay(u8),
+    Template(String),
+    Registry(PathBuf, String),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidYear(year) => write!(f, "there was no event in {}", year),
+            Self::InvalidDay(day) => write!(f, "day {} is not between 1 and 25", day),
+            Self::Template(e) => write!(f, "bad template: {}", e),
+            Self::Registry(path, e) => write!(f, "{}: {}", path.display(), e),
+            Self::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// One step taken by [`scaffold`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Step {
+    Created(PathBuf),
+    /// The file already existed and was left alone.
+    Kept(PathBuf),
+    Registered(PathBuf),
+    AlreadyRegistered(PathBuf),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Created(path) => write!(f, "created {}", path.display()),
+            Self::Kept(path) => write!(f, "kept existing {}", path.display()),
+            Self::Registered(path) => write!(f, "registered in {}", path.display()),
+            Self::AlreadyRegistered(path) => {
+                write!(f, "already registered in {}", path.display())
+            }
+        }
+    }
+}
+
+/// Fills in `{{name}}` placeholders from `values`. Unknown or unterminated
+/// placeholders are errors rather than being copied through.
+pub fn render(template: &str, values: &[(&str, &str)]) -> Result<String, ScaffoldError> {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find("}}")
+            .ok_or_else(|| ScaffoldError::Template("unterminated placeholder".to_string()))?;
+        let key = &after[..end];
+        let value = values
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| ScaffoldError::Template(format!("unknown placeholder {:?}", key)))?;
+        output.push_str(value);
+        rest = &after[end + 2..];
+    }
+    output.push_str(rest);
+
+    Ok(output)
+}
+
+/// Writes `contents` to `path` unless something is already there.
+fn create(path: &Path, contents: &str) -> Result<Step, ScaffoldError> {
+    let io_error = |e| ScaffoldError::Io(path.to_path_buf(), e);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(mut file) => {
+            file.write_all(contents.as_bytes()).map_err(io_error)?;
+            Ok(Step::Created(path.to_path_buf()))
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(Step::Kept(path.to_path_buf())),
+        Err(e) => Err(io_error(e)),
+    }
+}
+
+/// Inserts `line` into the run of lines starting with `prefix`, keeping the
+/// run sorted. Returns `None` if `line` is already present.
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> Option<Result<String, String>> {
+    let lines = text.lines().collect::<Vec<_>>();
+    if lines.iter().any(|l| l.trim() == line.trim()) {
+        return None;
+    }
+
+    let run = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with(prefix))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let Some(&last) = run.last() else {
+        return Some(Err(format!("no lines starting with {:?}", prefix.trim())));
+    };
+
+    let at = run
+        .iter()
+        .copied()
+        .find(|&i| lines[i] > line)
+        .unwrap_or(last + 1);
+
+    let mut output = lines[..at].join("\n");
+    if at > 0 {
+        output.push('\n');
+    }
+    output.push_str(line);
+    output.push('\n');
+    for l in &lines[at..] {
+        output.push_str(l);
+        output.push('\n');
+    }
+    Some(Ok(output))
+}
+
+/// Adds each `(prefix, line)` to `path` with [`insert_sorted`], once.
+fn register(path: &Path, entries: &[(&str, String)]) -> Result<Step, ScaffoldError> {
+    let mut text =
+        fs::read_to_string(path).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))?;
+    let mut changed = false;
+
+    for (prefix, line) in entries {
+        if let Some(result) = insert_sorted(&text, prefix, line) {
+            text = result.map_err(|e| ScaffoldError::Registry(path.to_path_buf(), e))?;
+            changed = true;
+        }
+    }
+
+    if !changed {
+        return Ok(Step::AlreadyRegistered(path.to_path_buf()));
+    }
+    fs::write(path, text).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))?;
+    Ok(Step::Registered(path.to_path_buf()))
+}
+
+/// Puts each entry of a `DAYS` list that rustfmt has joined onto one line
+/// back on its own line, so that new entries can be sorted in.
+fn expand_days(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for line in text.lines() {
+        let entries = line
+            .split_once("= &[")
+            .filter(|(start, _)| start.contains("DAYS"))
+            .and_then(|(start, rest)| Some((start, rest.strip_suffix("];")?)));
+        match entries {
+            Some((start, entries)) if !entries.trim().is_empty() => {
+                output.push_str(start);
+                output.push_str("= &[\n");
+                for entry in entries.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+                    output.push_str(&format!("    {},\n", entry));
+                }
+                output.push_str("];\n");
+            }
+            _ => {
+                output.push_str(line);
+                output.push('\n');
+            }
+        }
+    }
+    output
+}
+
+/// Adds `pub mod dayNN;` and the `DAYS` entry to a year's `mod.rs`, once. A
+/// year without any days has nothing to sort against, so its first day is
+/// written out in full.
+fn register_day(module: &Path, day: u8) -> Result<Step, ScaffoldError> {
+    let io_error = |e| ScaffoldError::Io(module.to_path_buf(), e);
+
+    let text = fs::read_to_string(module).map_err(io_error)?;
+    if !text.contains("pub mod day") && text.contains("= &[];") {
+        let text = format!(
+            "pub mod day{:02};\n\n{}",
+            day,
+            text.replace(
+                "= &[];",
+                &format!("= &[\n    &day{:02}::Day{:02},\n];", day, day)
+            )
+        );
+        fs::write(module, text).map_err(io_error)?;
+        return Ok(Step::Registered(module.to_path_buf()));
+    }
+
+    let expanded = expand_days(&text);
+    if expanded != text {
+        fs::write(module, expanded).map_err(io_error)?;
+    }
+    register(
+        module,
+        &[
+            ("pub mod day", format!("pub mod day{:02};", day)),
+            ("    &day", format!("    &day{:02}::Day{:02},", day, day)),
+        ],
+    )
+}
+
+/// Creates the module and registry entry for a new day of `year` under
+/// `root`, along with the year's module if it's the first day of that year;
+/// the registry is all `benches/days.rs` needs to pick it up. Existing files
+/// are never overwritten, so this is safe to run again.
+pub fn scaffold(root: &Path, year: u16, day: u8, title: &str) -> Result<Vec<Step>, ScaffoldError> {
+    if year < 2015 {
+        return Err(ScaffoldError::InvalidYear(year));
+    }
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+
+    let year_number = year.to_string();
+    let day_number = day.to_string();
+    let padded = format!("{:02}", day);
+    let name = format!("Day{}", padded);
+    let title = title.escape_debug().to_string();
+    let values = [
+        ("year", year_number.as_str()),
+        ("day", day_number.as_str()),
+        ("dd", padded.as_str()),
+        ("name", name.as_str()),
+        ("title", title.as_str()),
+    ];
+
+    let dir = root.join("src").join(format!("y{}", year));
+    let year_module = dir.join("mod.rs");
+    let mut steps = Vec::new();
+
+    if !year_module.exists() {
+        steps.push(create(&year_module, YEAR_MODULE)?);
+        steps.push(register(
+            &root.join("src/lib.rs"),
+            &[
+                ("pub mod y", format!("pub mod y{};", year)),
+                ("    (", format!("    ({}, y{}::DAYS),", year, year)),
+            ],
+        )?);
+    }
+    steps.push(create(
+        &dir.join(format!("day{}.rs", padded)),
+        &render(DAY_TEMPLATE, &values)?,
+    )?);
+    steps.push(register_day(&year_module, day)?);
+
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::answers::KnownAnswers;
+    use crate::checks;
+    use crate::config::Config;
+    use crate::error::{DayError, ParseError};
+    use crate::params::NoParams;
+    use crate::solution::{Solution, Solver};
+    use crate::test_util::TempDir;
+
+    const LIB: &str = "pub mod answer;
+
+pub mod y2022;
+
+use solution::Solver;
+
+pub static YEARS: &[(u16, &[&dyn Solver])] = &[
+    (2022, y2022::DAYS),
+];
+";
+
+    const YEAR: &str = "pub mod day01;
+pub mod day15;
+
+use crate::solution::Solver;
+
+pub static DAYS: &[&dyn Solver] = &[
+    &day01::Day01,
+    &day15::Day15,
+];
+";
+
+    #[test]
+    fn test_render() {
+        let values = [("dd", "07"), ("name", "Day07")];
+
+        assert_eq!(
+            render("{{name}} reads input/{{dd}}.txt, not 01", &values).unwrap(),
+            "Day07 reads input/07.txt, not 01"
+        );
+        assert!(render("{{year}}", &values).is_err());
+        assert!(render("{{dd", &values).is_err());
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let dir = TempDir::new("scaffold");
+        dir.write("src/lib.rs", LIB);
+        dir.write("src/y2022/mod.rs", YEAR);
+
+        let steps = scaffold(dir.path(), 2022, 7, "No \"Space\"").unwrap();
+
+        assert_eq!(
+            steps,
+            vec![
+                Step::Created(dir.path().join("src/y2022/day07.rs")),
+                Step::Registered(dir.path().join("src/y2022/mod.rs")),
+            ]
+        );
+        let module = dir.read("src/y2022/day07.rs");
+        assert!(module.contains("impl Solution for Day07"));
+        assert!(module.contains("const YEAR: u16 = 2022;"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.contains("const TITLE: &'static str = \"No \\\"Space\\\"\";"));
+        assert_eq!(
+            dir.read("src/y2022/mod.rs"),
+            YEAR.replace("pub mod day15;", "pub mod day07;\npub mod day15;")
+                .replace("    &day15", "    &day07::Day07,\n    &day15")
+        );
+        assert_eq!(dir.read("src/lib.rs"), LIB);
+    }
+
+    #[test]
+    fn test_scaffold_new_year() {
+        let dir = TempDir::new("scaffold-year");
+        dir.write("src/lib.rs", LIB);
+        dir.write("src/y2022/mod.rs", YEAR);
+
+        let steps = scaffold(dir.path(), 2021, 3, "Binary Diagnostic").unwrap();
+
+        assert_eq!(
+            steps,
+            vec![
+                Step::Created(dir.path().join("src/y2021/mod.rs")),
+                Step::Registered(dir.path().join("src/lib.rs")),
+                Step::Created(dir.path().join("src/y2021/day03.rs")),
+                Step::Registered(dir.path().join("src/y2021/mod.rs")),
+            ]
+        );
+        assert_eq!(
+            dir.read("src/lib.rs"),
+            LIB.replace("pub mod y2022;", "pub mod y2021;\npub mod y2022;")
+                .replace("    (2022", "    (2021, y2021::DAYS),\n    (2022")
+        );
+        assert_eq!(
+            dir.read("src/y2021/mod.rs"),
+            "pub mod day03;\n\nuse crate::solution::Solver;\n\npub static DAYS: &[&dyn Solver] = &[\n    &day03::Day03,\n];\n"
+        );
+
+        // As left by rustfmt.
+        dir.write(
+            "src/y2021/mod.rs",
+            "pub mod day03;\n\nuse crate::solution::Solver;\n\npub static DAYS: &[&dyn Solver] = &[&day03::Day03];\n",
+        );
+        scaffold(dir.path(), 2021, 1, "Sonar Sweep").unwrap();
+        assert!(dir
+            .read("src/y2021/mod.rs")
+            .ends_with("= &[\n    &day01::Day01,\n    &day03::Day03,\n];\n"));
+    }
+
+    #[test]
+    fn test_scaffold_is_idempotent() {
+        let dir = TempDir::new("scaffold-again");
+        dir.write("src/lib.rs", LIB);
+        dir.write("src/y2022/mod.rs", YEAR);
+        dir.write("src/y2022/day16.rs", "// work in progress\n");
+
+        scaffold(dir.path(), 2022, 16, "Proboscidea Volcanium").unwrap();
+        let module = dir.read("src/y2022/mod.rs");
+        let steps = scaffold(dir.path(), 2022, 16, "Proboscidea Volcanium").unwrap();
+
+        assert_eq!(
+            steps,
+            vec![
+                Step::Kept(dir.path().join("src/y2022/day16.rs")),
+                Step::AlreadyRegistered(dir.path().join("src/y2022/mod.rs")),
+            ]
+        );
+        assert_eq!(dir.read("src/y2022/day16.rs"), "// work in progress\n");
+        assert_eq!(dir.read("src/y2022/mod.rs"), module);
+        assert!(module.ends_with("pub mod day16;\n\nuse crate::solution::Solver;\n\npub static DAYS: &[&dyn Solver] = &[\n    &day01::Day01,\n    &day15::Day15,\n    &day16::Day16,\n];\n"));
+    }
+
+    /// Stands in for the module the template renders, which can't be built
+    /// from within a test: it answers the same way.
+    struct Scaffolded;
+
+    impl Solution for Scaffolded {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Trebuchet?!";
+
+        type Input = Vec<String>;
+        type Params = NoParams;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(str::to_string).collect())
+        }
+
+        fn part1(_input: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+            Ok(Answer::Unsolved)
+        }
+
+        fn part2(_input: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+            Ok(Answer::Unsolved)
+        }
+    }
+
+    #[test]
+    fn test_scaffolded_day_passes_checks() {
+        let dir = TempDir::new("scaffold-checks");
+        dir.write("src/lib.rs", LIB);
+        dir.write("src/y2022/mod.rs", YEAR);
+        dir.write("src/y2022/day01.rs", "");
+        dir.write("src/y2022/day15.rs", "");
+
+        scaffold(dir.path(), 2023, 1, "Trebuchet?!").unwrap();
+        assert_eq!(
+            dir.read("src/y2023/day01.rs")
+                .matches("Ok(Answer::Unsolved)")
+                .count(),
+            2
+        );
+        // As `aoc new --fetch` leaves the day before it's solved.
+        dir.write("input/2023/01.txt", "1abc2\n");
+        dir.write(
+            "fixtures/2023/01.toml",
+            "[[examples]]\ninput = \"1abc2\\n\"\npart1 = 12\n",
+        );
+
+        let config = Config {
+            root: dir.path().to_path_buf(),
+            ..Config::default()
+        };
+        let known = KnownAnswers::default();
+        let solvers = [&Scaffolded as &dyn Solver];
+        for outcome in [
+            checks::registry(dir.path()),
+            checks::known_answers(&config, &known, &solvers),
+            checks::streamed_answers(&config, &known, &solvers),
+            checks::examples(&config, &solvers),
+            checks::generators(&solvers),
+        ] {
+            outcome.assert_passed();
+        }
+    }
+
+    #[test]
+    fn test_invalid_day() {
+        let dir = TempDir::new("scaffold-invalid");
+
+        assert!(matches!(
+            scaffold(dir.path(), 2022, 26, "Nope"),
+            Err(ScaffoldError::InvalidDay(26))
+        ));
+        assert!(matches!(
+            scaffold(dir.path(), 1999, 1, "Nope"),
+            Err(ScaffoldError::InvalidYear(1999))
+        ));
+    }
+}
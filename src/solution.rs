@@ -0,0 +1,285 @@
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError, ReadError};
+use crate::input;
+use crate::params::{Overrides, Params};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        part.number()
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = ParsePartError;
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        n.to_string().parse()
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePartError(String);
+
+impl fmt::Display for ParsePartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid part '{}', expected 1 or 2", self.0)
+    }
+}
+
+impl std::error::Error for ParsePartError {}
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            s => Err(ParsePartError(s.to_string())),
+        }
+    }
+}
+
+/// A step in running a day: parsing the input, or solving one part.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)];
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// A single day's puzzle. Parsing is kept separate from solving so that the
+/// parsed input can be shared between both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    /// Settings the parts take besides the input, or
+    /// [`NoParams`](crate::params::NoParams).
+    type Params: Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Answer, DayError>;
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Answer, DayError>;
+
+    /// Solves `part` from a stream. By default the whole input is read into
+    /// memory first; days that can work through it a line at a time override
+    /// this so that inputs too large to load can still be solved.
+    fn solve_reader(
+        reader: &mut dyn BufRead,
+        part: Part,
+        params: &Self::Params,
+    ) -> Result<Answer, ReadError>
+    where
+        Self: Sized,
+    {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(solve_with::<Self>(&input, part, params)?)
+    }
+}
+
+/// Normalizes and parses `input`, then solves a single part of `S` with the
+/// default parameters.
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<Answer, DayError> {
+    solve_with::<S>(input, part, &S::Params::default())
+}
+
+/// Like [`solve`], with the given parameters.
+pub fn solve_with<S: Solution>(
+    input: &str,
+    part: Part,
+    params: &S::Params,
+) -> Result<Answer, DayError> {
+    let input = S::parse(&input::normalize(input))?;
+    match part {
+        Part::One => S::part1(&input, params),
+        Part::Two => S::part2(&input, params),
+    }
+}
+
+/// Object safe view of a [`Solution`], used by the registry. Input is
+/// normalized before it reaches [`Solution::parse`], and parameters are given
+/// as [`Overrides`] of the day's defaults.
+pub trait Solver: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn parse_with(&self, input: &str, params: &Overrides) -> Result<Box<dyn Parsed>, ParseError>;
+    fn solve_reader(
+        &self,
+        reader: &mut dyn BufRead,
+        part: Part,
+        params: &Overrides,
+    ) -> Result<Answer, ReadError>;
+
+    /// The parameters `overrides` resolve to, as TOML.
+    fn params(&self, overrides: &Overrides) -> Result<String, ParseError>;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        self.parse_with(input, &Overrides::new())
+    }
+
+    fn solve(&self, input: &str, part: Part) -> Result<Answer, DayError> {
+        self.parse(input)?.solve(part)
+    }
+}
+
+/// Input that has been parsed by a [`Solver`] and is ready to be solved.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Result<Answer, DayError>;
+}
+
+struct ParsedInput<S: Solution>(S::Input, S::Params);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn solve(&self, part: Part) -> Result<Answer, DayError> {
+        match part {
+            Part::One => S::part1(&self.0, &self.1),
+            Part::Two => S::part2(&self.0, &self.1),
+        }
+    }
+}
+
+fn resolve<S: Solution>(overrides: &Overrides) -> Result<S::Params, ParseError> {
+    overrides
+        .resolve()
+        .map_err(|e| ParseError::input(S::DAY, format!("invalid parameters: {}", e)))
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Sync + 'static,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse_with(&self, input: &str, params: &Overrides) -> Result<Box<dyn Parsed>, ParseError> {
+        let params = resolve::<S>(params)?;
+        let input = S::parse(&input::normalize(input))?;
+        Ok(Box::new(ParsedInput::<S>(input, params)))
+    }
+
+    fn solve_reader(
+        &self,
+        reader: &mut dyn BufRead,
+        part: Part,
+        params: &Overrides,
+    ) -> Result<Answer, ReadError> {
+        S::solve_reader(reader, part, &resolve::<S>(params)?)
+    }
+
+    fn params(&self, overrides: &Overrides) -> Result<String, ParseError> {
+        let params = resolve::<S>(overrides)?;
+        toml::to_string(&params).map_err(|e| ParseError::input(S::DAY, e))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::params::NoParams;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Echo";
+
+        type Input = Vec<String>;
+        type Params = NoParams;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            let lines = input.lines().map(str::to_string).collect::<Vec<_>>();
+            if lines.len() < 2 {
+                return Err(ParseError::input(Self::DAY, "expected two lines"));
+            }
+            Ok(lines)
+        }
+
+        fn part1(input: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+            Ok(input[0].as_str().into())
+        }
+
+        fn part2(input: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+            Ok(input[1].as_str().into())
+        }
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver: &dyn Solver = &Echo;
+        let parsed = solver.parse("a\nb").unwrap();
+
+        assert_eq!(solver.day(), 0);
+        assert_eq!(solver.title(), "Echo");
+        assert_eq!(parsed.solve(Part::One).unwrap(), "a");
+        assert_eq!(parsed.solve(Part::Two).unwrap(), "b");
+        assert!(solver.solve("a", Part::One).is_err());
+        assert_eq!(
+            solver
+                .solve_reader(&mut "a\r\nb".as_bytes(), Part::Two, &Overrides::new())
+                .unwrap(),
+            "b"
+        );
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,471 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::http::{Client, HttpError};
+use crate::solution::Part;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Hint {
+    High,
+    Low,
+}
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "verdict", rename_all = "lowercase")]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+        #[serde(skip)]
+        wait: Option<Duration>,
+    },
+    /// Submitted during a cooldown; the answer was not checked.
+    Wait {
+        wait: Duration,
+    },
+    /// The part has already been solved, or isn't unlocked yet.
+    WrongLevel,
+    Unknown {
+        message: String,
+    },
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Incorrect { hint: None, .. } => write!(f, "incorrect"),
+            Self::Incorrect {
+                hint: Some(Hint::High),
+                ..
+            } => write!(f, "incorrect, too high"),
+            Self::Incorrect {
+                hint: Some(Hint::Low),
+                ..
+            } => write!(f, "incorrect, too low"),
+            Self::Wait { wait } => write!(f, "too soon, wait {}s", wait.as_secs()),
+            Self::WrongLevel => write!(f, "already solved or not unlocked"),
+            Self::Unknown { message } => write!(f, "unrecognised response: {}", message),
+        }
+    }
+}
+
+/// Reads the verdict out of the page returned for a submission.
+pub fn parse_response(body: &str) -> Verdict {
+    let message = article(body);
+
+    if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        let hint = if message.contains("too high") {
+            Some(Hint::High)
+        } else if message.contains("too low") {
+            Some(Hint::Low)
+        } else {
+            None
+        };
+        Verdict::Incorrect {
+            hint,
+            wait: parse_penalty(&message),
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::Wait {
+            wait: parse_left_to_wait(&message).unwrap_or(Duration::from_secs(60)),
+        }
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown { message }
+    }
+}
+
+/// The text of the page's `<article>`, or of the whole page, without tags.
+fn article(body: &str) -> String {
+    let body = match (body.find("<article"), body.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &body[start..end],
+        _ => body,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// "You have 4m 32s left to wait."
+fn parse_left_to_wait(message: &str) -> Option<Duration> {
+    let (before, _) = message.split_once(" left to wait")?;
+    let (_, amount) = before.rsplit_once("You have ")?;
+
+    let mut seconds = 0;
+    for token in amount.split_whitespace() {
+        let (n, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        seconds += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// "please wait one minute before trying again", "wait 5 minutes ..."
+fn parse_penalty(message: &str) -> Option<Duration> {
+    let lower = message.to_ascii_lowercase();
+    let (_, rest) = lower.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    #[serde(flatten)]
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted so far, and when the next may be sent.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    pub guesses: Vec<Guess>,
+    /// Seconds since the Unix epoch.
+    #[serde(default)]
+    pub wait_until: Option<u64>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text)
+                .map_err(|e| SubmitError::History(path.to_path_buf(), e.to_string())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(SubmitError::History(path.to_path_buf(), e.to_string())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), SubmitError> {
+        let json = serde_json::to_string_pretty(self).expect("history always serializes");
+        fs::write(path, json + "\n")
+            .map_err(|e| SubmitError::History(path.to_path_buf(), e.to_string()))
+    }
+
+    fn guesses(&self, year: u16, day: u8, part: Part) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |g| g.year == year && g.day == day && g.part == part)
+    }
+
+    /// Explains why `answer` is already known to be wrong, if it is.
+    fn check(&self, year: u16, day: u8, part: Part, answer: &str) -> Option<SubmitError> {
+        let value = answer.parse::<i64>().ok();
+
+        for guess in self.guesses(year, day, part) {
+            if guess.verdict == Verdict::Correct {
+                return Some(SubmitError::AlreadySolved(guess.answer.clone()));
+            }
+            if guess.answer == answer {
+                return Some(SubmitError::Duplicate(guess.clone()));
+            }
+            let Verdict::Incorrect {
+                hint: Some(hint), ..
+            } = guess.verdict
+            else {
+                continue;
+            };
+            let (Some(value), Ok(bound)) = (value, guess.answer.parse::<i64>()) else {
+                continue;
+            };
+            let excluded = match hint {
+                Hint::High => value >= bound,
+                Hint::Low => value <= bound,
+            };
+            if excluded {
+                return Some(SubmitError::OutOfBounds(guess.clone()));
+            }
+        }
+        None
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Http(HttpError),
+    Status(u16),
+    NotSubmittable(Answer),
+    Cooldown(Duration),
+    AlreadySolved(String),
+    Duplicate(Guess),
+    OutOfBounds(Guess),
+    History(PathBuf, String),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Http(e) => write!(f, "{}", e),
+            Self::Status(status) => write!(f, "server returned {}", status),
+            Self::NotSubmittable(answer) => {
+                write!(f, "answer cannot be submitted:\n{}", answer)
+            }
+            Self::Cooldown(wait) => write!(f, "wait {}s before submitting again", wait.as_secs()),
+            Self::AlreadySolved(answer) => write!(f, "already solved with {}", answer),
+            Self::Duplicate(guess) => {
+                write!(
+                    f,
+                    "{} was already submitted ({})",
+                    guess.answer, guess.verdict
+                )
+            }
+            Self::OutOfBounds(guess) => write!(
+                f,
+                "ruled out by the earlier guess {} ({})",
+                guess.answer, guess.verdict
+            ),
+            Self::History(path, e) => write!(f, "{}: {}", path.display(), e),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<HttpError> for SubmitError {
+    fn from(e: HttpError) -> Self {
+        Self::Http(e)
+    }
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Posts `answer` unless `history` shows it can't be right or a cooldown is
+/// still running, and records the outcome in `history`.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    now: SystemTime,
+) -> Result<Verdict, SubmitError> {
+    if !answer.is_solved() || answer.is_multiline() {
+        return Err(SubmitError::NotSubmittable(answer.clone()));
+    }
+    let answer = answer.to_string();
+
+    if let Some(error) = history.check(year, day, part, &answer) {
+        return Err(error);
+    }
+
+    let now = unix_seconds(now);
+    if let Some(until) = history.wait_until.filter(|&until| until > now) {
+        return Err(SubmitError::Cooldown(Duration::from_secs(until - now)));
+    }
+
+    let level = part.number().to_string();
+    let response = client.post_form(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &level), ("answer", &answer)],
+    )?;
+    if response.status != 200 {
+        return Err(SubmitError::Status(response.status));
+    }
+
+    let verdict = parse_response(&response.body);
+    history.wait_until = match &verdict {
+        Verdict::Wait { wait }
+        | Verdict::Incorrect {
+            wait: Some(wait), ..
+        } => Some(now + wait.as_secs()),
+        _ => None,
+    };
+    if matches!(verdict, Verdict::Correct | Verdict::Incorrect { .. }) {
+        history.guesses.push(Guess {
+            year,
+            day,
+            part,
+            answer,
+            verdict: verdict.clone(),
+        });
+    }
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::Config;
+    use crate::test_util::{FakeServer, TempDir};
+
+    fn page(message: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    fn client(server: &FakeServer) -> Client {
+        Client::new(&Config {
+            base_url: server.url.clone(),
+            session: Some("abc".to_string()),
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.")),
+            Verdict::Incorrect {
+                hint: Some(Hint::High),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer.  Please wait 5 minutes before trying again."
+            )),
+            Verdict::Incorrect {
+                hint: None,
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait.")),
+            Verdict::Wait {
+                wait: Duration::from_secs(272)
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Verdict::WrongLevel
+        );
+    }
+
+    #[test]
+    fn test_never_resubmits_wrong_answers() {
+        let server = FakeServer::new(|_| {
+            (
+                200,
+                page("That's not the right answer; your answer is too low."),
+            )
+        });
+        let client = client(&server);
+        let mut history = History::default();
+        let now = SystemTime::now();
+
+        let verdict = submit(&client, &mut history, 2022, 1, Part::One, &100.into(), now).unwrap();
+        assert_eq!(
+            verdict,
+            Verdict::Incorrect {
+                hint: Some(Hint::Low),
+                wait: None
+            }
+        );
+
+        let error = submit(&client, &mut history, 2022, 1, Part::One, &100.into(), now);
+        assert!(matches!(error, Err(SubmitError::Duplicate(_))));
+        let error = submit(&client, &mut history, 2022, 1, Part::One, &50.into(), now);
+        assert!(matches!(error, Err(SubmitError::OutOfBounds(_))));
+        assert_eq!(server.requests().len(), 1);
+
+        submit(&client, &mut history, 2022, 1, Part::Two, &100.into(), now).unwrap();
+        assert_eq!(server.requests().len(), 2);
+        assert_eq!(server.requests()[1].body, "level=2&answer=100");
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let server = FakeServer::new(|_| {
+            (
+                200,
+                page("You gave an answer too recently.  You have 30s left to wait."),
+            )
+        });
+        let client = client(&server);
+        let mut history = History::default();
+        let now = SystemTime::now();
+
+        let verdict = submit(&client, &mut history, 2022, 1, Part::One, &1.into(), now).unwrap();
+        assert_eq!(
+            verdict,
+            Verdict::Wait {
+                wait: Duration::from_secs(30)
+            }
+        );
+        assert!(history.guesses.is_empty());
+
+        let error = submit(&client, &mut history, 2022, 1, Part::One, &1.into(), now);
+        assert!(matches!(error, Err(SubmitError::Cooldown(_))));
+
+        let later = now + Duration::from_secs(31);
+        submit(&client, &mut history, 2022, 1, Part::One, &1.into(), later).unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let dir = TempDir::new("submit-history");
+        let path = dir.path().join("history.json");
+        let history = History {
+            guesses: vec![Guess {
+                year: 2022,
+                day: 4,
+                part: Part::Two,
+                answer: "914".to_string(),
+                verdict: Verdict::Incorrect {
+                    hint: Some(Hint::High),
+                    wait: None,
+                },
+            }],
+            wait_until: Some(1234),
+        };
+
+        history.save(&path).unwrap();
+        let loaded = History::load(&path).unwrap();
+
+        assert_eq!(loaded.guesses, history.guesses);
+        assert_eq!(loaded.wait_until, Some(1234));
+        assert!(dir.read("history.json").contains("\"hint\": \"high\""));
+    }
+}
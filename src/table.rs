@@ -0,0 +1,49 @@
+/// Lays out `rows` in columns, with a rule under the first.
+pub fn align<const N: usize>(rows: &[[String; N]]) -> String {
+    let mut widths = [0; N];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for (i, row) in rows.iter().enumerate() {
+        let cells = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+        if i == 0 {
+            let rule = widths.map(|width| "-".repeat(width));
+            table.push_str(&rule.join("  "));
+            table.push('\n');
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_align() {
+        let rows = [
+            ["Day", "Title", "Answer"].map(String::from),
+            ["01", "Calorie Counting", "24000"].map(String::from),
+            ["10", "Cathode-Ray Tube", ""].map(String::from),
+        ];
+
+        assert_eq!(
+            align(&rows),
+            "Day  Title             Answer
+---  ----------------  ------
+01   Calorie Counting  24000
+10   Cathode-Ray Tube
+"
+        );
+    }
+}
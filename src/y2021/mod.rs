@@ -0,0 +1,3 @@
+use crate::solution::Solver;
+
+pub static DAYS: &[&dyn Solver] = &[];
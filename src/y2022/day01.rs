@@ -0,0 +1,162 @@
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError, ReadError, Source};
+use crate::input::Lines;
+use crate::params::NoParams;
+use crate::solution::{self, Part, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Input = Vec<u32>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let mut elves = vec![0_u32];
+
+        for line in input.lines() {
+            if line.is_empty() {
+                elves.push(0);
+                continue;
+            }
+
+            let calories = source.parse::<u32>(line)?;
+            let elf = elves.last_mut().unwrap();
+            *elf = elf
+                .checked_add(calories)
+                .ok_or_else(|| source.error(line, "calorie total overflows"))?;
+        }
+
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let result = elves.iter().copied().fold(u32::MIN, u32::max);
+
+        Ok(result.into())
+    }
+
+    fn part2(elves: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let result: u64 = elves
+            .iter()
+            .copied()
+            .fold(vec![0; 3], |mut vec, next: u32| {
+                vec.push(next);
+                vec.sort();
+                vec.reverse();
+                vec.pop();
+                vec
+            })
+            .into_iter()
+            .map(u64::from)
+            .sum();
+
+        Ok(result.into())
+    }
+
+    /// Keeps only the running total and the three largest so far.
+    fn solve_reader(
+        reader: &mut dyn BufRead,
+        part: Part,
+        _: &Self::Params,
+    ) -> Result<Answer, ReadError> {
+        let mut lines = Lines::new(reader);
+        let mut top = [0_u32; 3];
+        let mut elf = 0_u32;
+
+        while let Some((number, line)) = lines.next_line()? {
+            if line.is_empty() {
+                record(&mut top, elf);
+                elf = 0;
+                continue;
+            }
+
+            let source = Source::new(Self::DAY, line);
+            let calories = source.parse::<u32>(line).map_err(|e| e.on_line(number))?;
+            elf = elf.checked_add(calories).ok_or_else(|| {
+                source
+                    .error(line, "calorie total overflows")
+                    .on_line(number)
+            })?;
+        }
+        record(&mut top, elf);
+
+        match part {
+            Part::One => Ok(top[0].into()),
+            Part::Two => Ok(top.into_iter().map(u64::from).sum::<u64>().into()),
+        }
+    }
+}
+
+/// Inserts `calories` into `top`, which is sorted largest first.
+fn record(top: &mut [u32; 3], calories: u32) {
+    if let Some(i) = top.iter().position(|&c| calories > c) {
+        top[i..].rotate_right(1);
+        top[i] = calories;
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day01>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day01>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "24000");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, "45000");
+    }
+
+    #[test]
+    fn test_stream() {
+        for part in Part::ALL {
+            let streamed = Day01::solve_reader(&mut INPUT.as_bytes(), part, &NoParams {}).unwrap();
+
+            assert_eq!(streamed, solution::solve::<Day01>(INPUT, part).unwrap());
+        }
+
+        let error =
+            Day01::solve_reader(&mut "1\n\n2x\n".as_bytes(), Part::One, &NoParams {}).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 01, line 3, column 1: expected u32: invalid digit found in string (\"2x\")"
+        );
+    }
+}
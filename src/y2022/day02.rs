@@ -0,0 +1,243 @@
+use std::cmp::Ordering;
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError, ReadError, Source};
+use crate::input::Lines;
+use crate::params::NoParams;
+use crate::solution::{self, Part, Solution};
+
+enum Choice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl From<char> for Choice {
+    fn from(c: char) -> Self {
+        match c {
+            'A' => Self::Rock,
+            'B' => Self::Paper,
+            'C' => Self::Scissors,
+            'X' => Self::Rock,
+            'Y' => Self::Paper,
+            'Z' => Self::Scissors,
+            _ => panic!("invalid char"),
+        }
+    }
+}
+
+fn to_ordering(c: char) -> Ordering {
+    match c {
+        'X' => Ordering::Less,
+        'Y' => Ordering::Equal,
+        'Z' => Ordering::Greater,
+        _ => panic!("invalid char"),
+    }
+}
+
+impl Choice {
+    pub fn cmp(&self, other: &Self) -> Ordering {
+        match self {
+            Self::Rock => match other {
+                Self::Rock => Ordering::Equal,
+                Self::Paper => Ordering::Less,
+                Self::Scissors => Ordering::Greater,
+            },
+            Self::Paper => match other {
+                Self::Rock => Ordering::Greater,
+                Self::Paper => Ordering::Equal,
+                Self::Scissors => Ordering::Less,
+            },
+            Self::Scissors => match other {
+                Self::Rock => Ordering::Less,
+                Self::Paper => Ordering::Greater,
+                Self::Scissors => Ordering::Equal,
+            },
+        }
+    }
+
+    pub fn target(&self, other: Ordering) -> Self {
+        match self {
+            Self::Rock => match other {
+                Ordering::Equal => Self::Rock,
+                Ordering::Less => Self::Scissors,
+                Ordering::Greater => Self::Paper,
+            },
+            Self::Paper => match other {
+                Ordering::Greater => Self::Scissors,
+                Ordering::Equal => Self::Paper,
+                Ordering::Less => Self::Rock,
+            },
+            Self::Scissors => match other {
+                Ordering::Less => Self::Paper,
+                Ordering::Greater => Self::Rock,
+                Ordering::Equal => Self::Scissors,
+            },
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Input = Vec<(char, char)>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| round(&source, line))
+            .collect()
+    }
+
+    fn part1(rounds: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let result: u64 = rounds.iter().map(|round| u64::from(score1(round))).sum();
+
+        Ok(result.into())
+    }
+
+    fn part2(rounds: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let result: u64 = rounds.iter().map(|round| u64::from(score2(round))).sum();
+
+        Ok(result.into())
+    }
+
+    /// Scores each round as it's read.
+    fn solve_reader(
+        reader: &mut dyn BufRead,
+        part: Part,
+        _: &Self::Params,
+    ) -> Result<Answer, ReadError> {
+        let score = match part {
+            Part::One => score1,
+            Part::Two => score2,
+        };
+        let mut lines = Lines::new(reader);
+        let mut result = 0_u64;
+
+        while let Some((number, line)) = lines.next_line()? {
+            if line.is_empty() {
+                continue;
+            }
+            let source = Source::new(Self::DAY, line);
+            let round = round(&source, line).map_err(|e| e.on_line(number))?;
+            result += u64::from(score(&round));
+        }
+
+        Ok(result.into())
+    }
+}
+
+/// Reads a round such as `A Y`. Some inputs leave out the space, as in `CZ`.
+fn round(source: &Source, line: &str) -> Result<(char, char), ParseError> {
+    let split = line.char_indices().nth(1).map_or(line.len(), |(i, _)| i);
+    let (them, us) = line.split_at(split);
+    let us = us.strip_prefix(' ').unwrap_or(us);
+    let them = column(source, them, "ABC")?;
+    let us = column(source, us, "XYZ")?;
+    Ok((them, us))
+}
+
+fn score1(&(them, us): &(char, char)) -> u32 {
+    let them: Choice = them.into();
+    let us: Choice = us.into();
+
+    match us.cmp(&them) {
+        Ordering::Greater => 6 + us.value(),
+        Ordering::Equal => 3 + us.value(),
+        Ordering::Less => us.value(),
+    }
+}
+
+fn score2(&(them, outcome): &(char, char)) -> u32 {
+    let them: Choice = them.into();
+    let target = to_ordering(outcome);
+    let us = them.target(target);
+
+    match target {
+        Ordering::Greater => 6 + us.value(),
+        Ordering::Equal => 3 + us.value(),
+        Ordering::Less => us.value(),
+    }
+}
+
+/// Reads one column of a round, which must be a single letter from `allowed`.
+fn column(source: &Source, token: &str, allowed: &str) -> Result<char, ParseError> {
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if allowed.contains(c) => Ok(c),
+        _ => Err(source.error(token, format!("expected one of {}", allowed))),
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day02>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day02>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "A Y
+B X
+C Z";
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "15");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, "12");
+    }
+
+    #[test]
+    fn test_missing_space() {
+        assert_eq!(part1("A Y\nCZ").unwrap(), part1("A Y\nC Z").unwrap());
+        assert_eq!(
+            part1("A Y\nC").unwrap_err().to_string(),
+            "day 02, line 2, column 2: expected one of XYZ"
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        for part in Part::ALL {
+            let streamed = Day02::solve_reader(&mut INPUT.as_bytes(), part, &NoParams {}).unwrap();
+
+            assert_eq!(streamed, solution::solve::<Day02>(INPUT, part).unwrap());
+        }
+
+        let error =
+            Day02::solve_reader(&mut "A Y\nB Q\n".as_bytes(), Part::Two, &NoParams {}).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 02, line 2, column 3: expected one of XYZ (\"Q\")"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError, ReadError, Source};
+use crate::input::Lines;
+use crate::params::NoParams;
+use crate::solution::{self, Part, Solution};
+
+fn priority(letter: char) -> u32 {
+    match letter.is_ascii_uppercase() {
+        true => letter as u32 - 38,
+        _ => letter as u32 - 96,
+    }
+}
+
+fn missing(line: usize, items: &[char], message: &str) -> ParseError {
+    ParseError::at_line(Day03::DAY, line, items.iter().collect::<String>(), message)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input = Vec<Vec<char>>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(Self::DAY, input);
+
+        input.lines().map(|line| rucksack(&source, line)).collect()
+    }
+
+    fn part1(rucksacks: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let result = rucksacks
+            .iter()
+            .enumerate()
+            .map(|(i, items)| shared_item(i + 1, items).map(u64::from))
+            .sum::<Result<u64, ParseError>>()?;
+
+        Ok(result.into())
+    }
+
+    fn part2(rucksacks: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let result = rucksacks
+            .chunks(3)
+            .enumerate()
+            .map(|(i, group)| badge(i * 3 + 1, group).map(u64::from))
+            .sum::<Result<u64, ParseError>>()?;
+
+        Ok(result.into())
+    }
+
+    /// Holds at most one group of rucksacks at a time.
+    fn solve_reader(
+        reader: &mut dyn BufRead,
+        part: Part,
+        _: &Self::Params,
+    ) -> Result<Answer, ReadError> {
+        let mut lines = Lines::new(reader);
+        let mut group = Vec::with_capacity(3);
+        let mut first = 1;
+        let mut result = 0_u64;
+
+        while let Some((number, line)) = lines.next_line()? {
+            let source = Source::new(Self::DAY, line);
+            let items = rucksack(&source, line).map_err(|e| e.on_line(number))?;
+
+            let priority = match part {
+                Part::One => shared_item(number, &items)?,
+                Part::Two => {
+                    if group.is_empty() {
+                        first = number;
+                    }
+                    group.push(items);
+                    if group.len() < 3 {
+                        continue;
+                    }
+                    let priority = badge(first, &group)?;
+                    group.clear();
+                    priority
+                }
+            };
+            result += u64::from(priority);
+        }
+        if !group.is_empty() {
+            result += u64::from(badge(first, &group)?);
+        }
+
+        Ok(result.into())
+    }
+}
+
+fn rucksack(source: &Source, line: &str) -> Result<Vec<char>, ParseError> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        return Err(source.error(&line[i..i + c.len_utf8()], "expected an item letter"));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(source.error(line, "compartments must be the same size"));
+    }
+    Ok(line.chars().collect())
+}
+
+/// The priority of the item in both compartments of the rucksack on `line`.
+fn shared_item(line: usize, items: &[char]) -> Result<u32, ParseError> {
+    if items.is_empty() {
+        return Ok(0);
+    }
+
+    let (left, right) = items.split_at(items.len() / 2);
+    let letter = left
+        .iter()
+        .find(|l| right.contains(l))
+        .copied()
+        .ok_or_else(|| missing(line, items, "no item is in both compartments"))?;
+
+    Ok(priority(letter))
+}
+
+/// The priority of the badge of the group starting on `line`, which must
+/// hold three rucksacks.
+fn badge(line: usize, group: &[Vec<char>]) -> Result<u32, ParseError> {
+    if group.len() != 3 {
+        let message = format!("group has {} rucksacks, expected 3", group.len());
+        return Err(missing(line, &group[0], &message));
+    }
+
+    let letter = group[0]
+        .iter()
+        .find(|&c| group[1..].iter().all(|v| v.contains(c)))
+        .copied()
+        .ok_or_else(|| missing(line, &group[0], "group has no common badge"))?;
+
+    Ok(priority(letter))
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day03>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day03>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "157");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, "70");
+    }
+
+    #[test]
+    fn test_stream() {
+        for part in Part::ALL {
+            let streamed = Day03::solve_reader(&mut INPUT.as_bytes(), part, &NoParams {}).unwrap();
+
+            assert_eq!(streamed, solution::solve::<Day03>(INPUT, part).unwrap());
+        }
+
+        let input = format!("{}\nab\ncd\nef\n", INPUT);
+        let error =
+            Day03::solve_reader(&mut input.as_bytes(), Part::Two, &NoParams {}).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 03, line 7, column 1: group has no common badge (\"ab\")"
+        );
+    }
+
+    #[test]
+    fn test_incomplete_group() {
+        let input = format!(
+            "{}
+vJrwpWtwJgWrhcsFMMfFFhFp",
+            INPUT
+        );
+        let expected = "day 03, line 7, column 1: group has 1 rucksacks, expected 3 \
+                        (\"vJrwpWtwJgWrhcsFMMfFFhFp\")";
+
+        assert_eq!(part2(&input).unwrap_err().to_string(), expected);
+        let error =
+            Day03::solve_reader(&mut input.as_bytes(), Part::Two, &NoParams {}).unwrap_err();
+        assert_eq!(error.to_string(), expected);
+        assert!(part1(&input).is_ok());
+    }
+}
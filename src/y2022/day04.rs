@@ -0,0 +1,104 @@
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError, Source};
+use crate::params::NoParams;
+use crate::pattern::{Captures, Pattern};
+use crate::range_set::Interval;
+use crate::solution::{self, Part, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input = Vec<(Interval, Interval)>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let pattern = Pattern::new("{a}-{b},{c}-{d}");
+        let interval = |captures: &Captures, start, end| -> Result<Interval, ParseError> {
+            let (start, end) = (captures.get(start), captures.get(end));
+            match (source.parse::<u32>(start)?, source.parse::<u32>(end)?) {
+                (min, max) if min <= max => Ok(Interval::new(min.into(), max.into())),
+                _ => Err(source.error(end, format!("expected at least {}", start))),
+            }
+        };
+
+        input
+            .lines()
+            .map(|line| {
+                let captures = pattern.captures(&source, line)?;
+                Ok((
+                    interval(&captures, "a", "b")?,
+                    interval(&captures, "c", "d")?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let result = pairs
+            .iter()
+            .filter(|(left, right)| left.contains_interval(right) || right.contains_interval(left))
+            .count();
+
+        Ok(result.into())
+    }
+
+    fn part2(pairs: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let result = pairs
+            .iter()
+            .filter(|(left, right)| left.overlaps(right))
+            .count();
+
+        Ok(result.into())
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day04>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day04>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "2");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, "4");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day04::parse("2-4,6-8\n2-3,4").unwrap_err();
+
+        assert_eq!((error.day, error.line, error.column), (4, 2, 6));
+        assert_eq!(error.message, "expected \"-\"");
+
+        let error = Day04::parse("4-2,6-8").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (3, "2"));
+        assert_eq!(error.message, "expected at least 4");
+    }
+}
@@ -0,0 +1,192 @@
+use crate::answer::Answer;
+use crate::error::{end_of, DayError, ParseError, Source};
+use crate::params::NoParams;
+use crate::pattern::Pattern;
+use crate::solution::{self, Part, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Procedure {
+    stacks: Vec<Vec<char>>,
+    instructions: Vec<(usize, usize, usize)>,
+}
+
+fn read(input: &str) -> Result<Procedure, ParseError> {
+    let source = Source::new(Day05::DAY, input);
+    let (state, instructions) = source.split_once(input, "\n\n")?;
+
+    let mut state = state.lines().rev();
+    let header = state.next().unwrap_or_default();
+    let count = header.split_whitespace().count();
+    if count == 0 {
+        return Err(source.error(header, "expected stack numbers"));
+    }
+
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); count];
+    for row in state {
+        for (n, (i, c)) in row.char_indices().enumerate().skip(1).step_by(4) {
+            let stack = n / 4;
+            if c == ' ' {
+                continue;
+            }
+            if stack >= count || !c.is_ascii_alphanumeric() {
+                return Err(source.error(&row[i..i + c.len_utf8()], "expected a crate"));
+            }
+            stacks[stack].push(c);
+        }
+    }
+
+    // Track stack heights so moves from an empty stack are reported here
+    // rather than when solving.
+    let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+    let pattern = Pattern::new("move {qty} from {src} to {dst}");
+    let instructions = instructions
+        .lines()
+        .map(|line| {
+            let captures = pattern.captures(&source, line)?;
+            let (qty, src, dst) = (
+                captures.get("qty"),
+                captures.get("src"),
+                captures.get("dst"),
+            );
+
+            let stack = |token: &str| match source.parse::<usize>(token)? {
+                n if (1..=count).contains(&n) => Ok(n),
+                _ => Err(source.error(token, format!("expected a stack from 1 to {}", count))),
+            };
+            let (qty_n, src_n, dst_n) = (source.parse::<usize>(qty)?, stack(src)?, stack(dst)?);
+
+            if heights[src_n - 1] < qty_n {
+                return Err(source.error(
+                    qty,
+                    format!("stack {} only holds {} crates", src_n, heights[src_n - 1]),
+                ));
+            }
+            heights[src_n - 1] -= qty_n;
+            heights[dst_n - 1] += qty_n;
+
+            Ok((qty_n, src_n, dst_n))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if instructions.is_empty() {
+        return Err(source.error(end_of(input), "expected instructions"));
+    }
+
+    Ok(Procedure {
+        stacks,
+        instructions,
+    })
+}
+
+fn tops(state: Vec<Vec<char>>) -> Answer {
+    state
+        .into_iter()
+        .filter_map(|mut stack| stack.pop())
+        .collect::<String>()
+        .into()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input = Procedure;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(procedure: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let mut state = procedure.stacks.clone();
+
+        procedure.instructions.iter().for_each(|&(qty, src, dst)| {
+            for _ in 0..qty {
+                let src_v = &mut state[src - 1];
+                let val = src_v.pop().expect("checked while parsing");
+                let dst_v = &mut state[dst - 1];
+                dst_v.push(val);
+            }
+        });
+
+        Ok(tops(state))
+    }
+
+    fn part2(procedure: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let mut state = procedure.stacks.clone();
+
+        procedure.instructions.iter().for_each(|&(qty, src, dst)| {
+            let mut transfer_stack: Vec<char> = Vec::with_capacity(qty);
+            for _ in 0..qty {
+                let src_v = &mut state[src - 1];
+                let val = src_v.pop().expect("checked while parsing");
+                transfer_stack.push(val)
+            }
+
+            for _ in 0..qty {
+                let val = transfer_stack.pop().unwrap();
+                let dst_v = &mut state[dst - 1];
+                dst_v.push(val);
+            }
+        });
+
+        Ok(tops(state))
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day05>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day05>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "CMZ");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, "MCD");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("move 3 from 1", "move 4 from 1");
+        let error = Day05::parse(&input).unwrap_err();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (7, 6, "4"));
+        assert_eq!(error.message, "stack 1 only holds 3 crates");
+
+        let input = INPUT.replace("from 2 to 1", "from 2 to 4");
+        let error = Day05::parse(&input).unwrap_err();
+
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (6, 18, "4")
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError, ReadError, SolveError, Source};
+use crate::params::Params;
+use crate::solution::{self, Part, Solution};
+
+/// How many different characters the signal can hold, and so the longest
+/// possible marker.
+const LETTERS: usize = 26;
+
+fn to_val(c: u8) -> usize {
+    (c - b'a') as usize
+}
+
+/// The last few characters of the signal, which form a marker once they're
+/// all different.
+struct Window {
+    length: usize,
+    counts: [usize; LETTERS],
+    seq: VecDeque<u8>,
+    position: usize,
+}
+
+impl Window {
+    fn new(length: usize) -> Self {
+        Self {
+            length,
+            counts: [0; LETTERS],
+            seq: VecDeque::with_capacity(length.min(LETTERS)),
+            position: 0,
+        }
+    }
+
+    /// Adds the next lowercase letter, returning the position just past the
+    /// marker if it completes one.
+    fn push(&mut self, c: u8) -> Option<usize> {
+        if self.seq.len() >= self.length {
+            let dec = self.seq.pop_front().unwrap();
+            self.counts[to_val(dec)] -= 1;
+        }
+
+        self.counts[to_val(c)] += 1;
+        self.seq.push_back(c);
+        self.position += 1;
+
+        let pass = self.seq.len() >= self.length
+            && self.seq.iter().all(|val| self.counts[to_val(*val)] == 1);
+        pass.then_some(self.position)
+    }
+}
+
+/// How many distinct characters make each kind of marker.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Markers {
+    pub packet: usize,
+    pub message: usize,
+}
+
+impl Default for Markers {
+    fn default() -> Self {
+        Self {
+            packet: 4,
+            message: 14,
+        }
+    }
+}
+
+impl Params for Markers {
+    fn validate(&self) -> Result<(), String> {
+        let lengths = 1..=LETTERS;
+        match lengths.contains(&self.packet) && lengths.contains(&self.message) {
+            true => Ok(()),
+            false => Err(format!("markers must be 1 to {} characters long", LETTERS)),
+        }
+    }
+}
+
+impl Markers {
+    fn length(&self, part: Part) -> usize {
+        match part {
+            Part::One => self.packet,
+            Part::Two => self.message,
+        }
+    }
+}
+
+fn no_marker(part: Part, length: usize) -> SolveError {
+    SolveError::new(
+        Day06::DAY,
+        part,
+        format!("no marker of {} distinct characters", length),
+    )
+}
+
+fn find_marker(input: &str, part: Part, markers: &Markers) -> Result<Answer, SolveError> {
+    let length = markers.length(part);
+    let mut window = Window::new(length);
+
+    input
+        .bytes()
+        .find_map(|c| window.push(c))
+        .map(Answer::from)
+        .ok_or_else(|| no_marker(part, length))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    type Input = String;
+    type Params = Markers;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let signal = input.trim_end();
+
+        if let Some((i, c)) = signal.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(source.error(&signal[i..i + c.len_utf8()], "expected a lowercase letter"));
+        }
+
+        Ok(signal.to_string())
+    }
+
+    fn part1(input: &Self::Input, markers: &Markers) -> Result<Answer, DayError> {
+        Ok(find_marker(input, Part::One, markers)?)
+    }
+
+    fn part2(input: &Self::Input, markers: &Markers) -> Result<Answer, DayError> {
+        Ok(find_marker(input, Part::Two, markers)?)
+    }
+
+    /// Reads the signal a buffer at a time and stops as soon as the marker is
+    /// found, so anything after it isn't checked.
+    fn solve_reader(
+        reader: &mut dyn BufRead,
+        part: Part,
+        markers: &Markers,
+    ) -> Result<Answer, ReadError> {
+        let length = markers.length(part);
+        let mut window = Window::new(length);
+        let mut column = 0;
+        // The first whitespace after the last letter, which is only allowed
+        // at the end of the input.
+        let mut trailing = None;
+        let mut start = true;
+
+        loop {
+            let buf = reader.fill_buf()?;
+            if buf.is_empty() {
+                return Err(no_marker(part, length).into());
+            }
+
+            let skip = if start && buf.starts_with("\u{feff}".as_bytes()) {
+                3
+            } else {
+                0
+            };
+            start = false;
+
+            for (i, &c) in buf.iter().enumerate().skip(skip) {
+                // Count characters rather than bytes, like the columns in
+                // other errors.
+                if c & 0xc0 != 0x80 {
+                    column += 1;
+                }
+                match c {
+                    b'a'..=b'z' if trailing.is_none() => {
+                        if let Some(position) = window.push(c) {
+                            return Ok(position.into());
+                        }
+                    }
+                    _ if c.is_ascii_whitespace() => {
+                        trailing.get_or_insert((column, c as char));
+                    }
+                    _ => {
+                        let (column, c) = trailing.unwrap_or_else(|| {
+                            let len = match c {
+                                0xf0.. => 4,
+                                0xe0.. => 3,
+                                0xc0.. => 2,
+                                _ => 1,
+                            };
+                            let bytes = &buf[i..buf.len().min(i + len)];
+                            let c = String::from_utf8_lossy(bytes).chars().next().unwrap();
+                            (column, c)
+                        });
+                        return Err(ParseError {
+                            day: Self::DAY,
+                            line: 1,
+                            column,
+                            text: c.to_string(),
+                            message: "expected a lowercase letter".to_string(),
+                        }
+                        .into());
+                    }
+                }
+            }
+
+            let len = buf.len();
+            reader.consume(len);
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day06>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day06>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "7");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, "19");
+    }
+
+    #[test]
+    fn test_markers() {
+        let markers = Markers {
+            packet: 3,
+            message: 5,
+        };
+
+        let result = solution::solve_with::<Day06>(INPUT, Part::One, &markers).unwrap();
+        assert_eq!(result, "3");
+        let result = solution::solve_with::<Day06>(INPUT, Part::Two, &markers).unwrap();
+        assert_eq!(result, "8");
+
+        let markers = Markers {
+            message: 100_000_000_000_000,
+            ..markers
+        };
+        assert!(markers.validate().is_err());
+    }
+
+    #[test]
+    fn test_stream() {
+        for part in Part::ALL {
+            let streamed =
+                Day06::solve_reader(&mut INPUT.as_bytes(), part, &Markers::default()).unwrap();
+
+            assert_eq!(streamed, solution::solve::<Day06>(INPUT, part).unwrap());
+        }
+
+        for input in ["abc\nabcd", "abcé", "abc"] {
+            let streamed =
+                Day06::solve_reader(&mut input.as_bytes(), Part::One, &Markers::default())
+                    .unwrap_err();
+
+            assert_eq!(streamed.to_string(), part1(input).unwrap_err().to_string());
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use std::cell::RefCell;
+use std::iter;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError, SolveError, Source};
+use crate::params::Params;
+use crate::solution::{self, Part, Solution};
+
+#[derive(Debug)]
+pub struct Node {
+    name: String,
+    node_type: NodeType,
+}
+
+#[derive(Debug)]
+pub enum NodeType {
+    Dir(Vec<Rc<RefCell<Node>>>),
+    File(usize),
+}
+
+pub fn directories(node: &Rc<RefCell<Node>>) -> Vec<Rc<RefCell<Node>>> {
+    match &node.borrow().node_type {
+        NodeType::File(_) => Vec::new(),
+        NodeType::Dir(children) => {
+            let me = iter::once(node.clone());
+
+            let children = children.iter().flat_map(directories);
+
+            me.chain(children).collect()
+        }
+    }
+}
+
+pub fn size(node: &Rc<RefCell<Node>>) -> usize {
+    match &node.borrow().node_type {
+        NodeType::File(size) => *size,
+        NodeType::Dir(children) => children.iter().map(size).sum::<usize>(),
+    }
+}
+
+impl Node {
+    // pub fn child_by_name(&self, name: &str) -> Option<Rc<RefCell<Node>>> {
+    //     match &self.node_type {
+    //         NodeType::File(_) => None,
+    //         NodeType::Dir(dir) => {
+    //             dir.iter().find(|node| node.borrow().name == name).cloned()
+    //         }
+    //     }
+    // }
+
+    pub fn child_by_name_or_create(&mut self, name: &str) -> Option<Rc<RefCell<Node>>> {
+        match &mut self.node_type {
+            NodeType::File(_) => None,
+            NodeType::Dir(ref mut dir) => {
+                match dir.iter().find(|node| node.borrow().name == name) {
+                    Some(node) => Some(node.clone()),
+                    None => {
+                        let node = Node {
+                            name: name.to_string(),
+                            ..Node::default()
+                        };
+
+                        let node = Rc::new(RefCell::new(node));
+                        dir.push(node.clone());
+
+                        Some(node)
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Self {
+            name: "/".to_string(),
+            node_type: NodeType::Dir(Vec::new()),
+        }
+    }
+}
+
+/// How deep directories may nest. The tree is walked recursively, so this
+/// keeps deep transcripts from overflowing the stack.
+const MAX_DEPTH: usize = 1000;
+
+pub fn read(input: &str) -> Result<Rc<RefCell<Node>>, ParseError> {
+    let source = Source::new(Day07::DAY, input);
+    let tree = Rc::new(RefCell::new(Node::default()));
+    let mut current_node: Rc<RefCell<Node>> = tree.clone();
+    let mut dir_stack: Vec<Rc<RefCell<Node>>> = Vec::new();
+    let mut total_size: usize = 0;
+
+    for line in input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut tokens = line.split(' ');
+
+        if let Some(command) = line.strip_prefix("$ ") {
+            let mut args = command.split(' ');
+            match source.next(&mut args, line, "a command")? {
+                "cd" => match source.next(&mut args, line, "a directory")? {
+                    "/" => {
+                        current_node = tree.clone();
+                        dir_stack.clear();
+                    }
+                    ".." => {
+                        current_node = dir_stack
+                            .pop()
+                            .ok_or_else(|| source.error(line, "already at the root directory"))?
+                    }
+                    path => {
+                        if dir_stack.len() == MAX_DEPTH {
+                            return Err(source.error(
+                                path,
+                                format!("directories nest more than {} deep", MAX_DEPTH),
+                            ));
+                        }
+                        let new_dir = current_node
+                            .borrow_mut()
+                            .child_by_name_or_create(path)
+                            .expect("current node is always a directory");
+                        if let NodeType::File(_) = new_dir.borrow().node_type {
+                            return Err(source.error(path, "not a directory"));
+                        }
+                        dir_stack.push(current_node);
+                        current_node = new_dir;
+                    }
+                },
+                "ls" => {}
+                s => return Err(source.error(s, "unexpected command")),
+            }
+            continue;
+        }
+
+        let arg1 = source.next(&mut tokens, line, "a listing")?;
+        let name = source.next(&mut tokens, line, "a name")?;
+
+        let node_type = match arg1 {
+            "dir" => NodeType::Dir(Vec::new()),
+            size => {
+                let size = source.parse::<usize>(size)?;
+                total_size = total_size
+                    .checked_add(size)
+                    .ok_or_else(|| source.error(line, "total size overflows"))?;
+                NodeType::File(size)
+            }
+        };
+
+        let node = Node {
+            name: name.to_string(),
+            node_type,
+        };
+
+        if let NodeType::Dir(dir) = &mut current_node.borrow_mut().node_type {
+            if !dir.iter().any(|child| child.borrow().name == name) {
+                dir.push(Rc::new(RefCell::new(node)));
+            }
+        }
+    }
+
+    Ok(tree)
+}
+
+/// Sizes in bytes that the puzzle asks about.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Disk {
+    /// Directories of at most this size are summed in part 1.
+    pub small_size: usize,
+    pub disk_size: usize,
+    /// The free space the update needs.
+    pub update_size: usize,
+}
+
+impl Default for Disk {
+    fn default() -> Self {
+        Self {
+            small_size: 100000,
+            disk_size: 70000000,
+            update_size: 30000000,
+        }
+    }
+}
+
+impl Params for Disk {
+    fn validate(&self) -> Result<(), String> {
+        match self.update_size <= self.disk_size {
+            true => Ok(()),
+            false => Err("the update must fit on the disk".to_string()),
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    type Input = Rc<RefCell<Node>>;
+    type Params = Disk;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(tree: &Self::Input, disk: &Disk) -> Result<Answer, DayError> {
+        let dirs = directories(tree);
+        let result = dirs
+            .iter()
+            .map(size)
+            .filter(|&s| s <= disk.small_size)
+            .sum::<usize>();
+        Ok(result.into())
+    }
+
+    fn part2(tree: &Self::Input, disk: &Disk) -> Result<Answer, DayError> {
+        let dirs = directories(tree);
+
+        let total_size = size(tree);
+        let free_space = disk.disk_size.checked_sub(total_size).ok_or_else(|| {
+            SolveError::new(
+                Self::DAY,
+                Part::Two,
+                format!("{} bytes do not fit on the disk", total_size),
+            )
+        })?;
+        let needed_space = disk.update_size.saturating_sub(free_space);
+        let mut candidates = dirs
+            .iter()
+            .map(size)
+            .filter(|&s| s >= needed_space)
+            .collect::<Vec<_>>();
+        candidates.sort();
+        let result = *candidates
+            .first()
+            .expect("the root directory is always a candidate");
+
+        Ok(result.into())
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day07>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day07>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "95437");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, "24933642");
+    }
+    #[test]
+    fn test_disk() {
+        let disk = Disk {
+            small_size: 1000,
+            disk_size: 50000000,
+            update_size: 25000000,
+        };
+
+        let result = solution::solve_with::<Day07>(INPUT, Part::One, &disk).unwrap();
+        assert_eq!(result, "584");
+        let result = solution::solve_with::<Day07>(INPUT, Part::Two, &disk).unwrap();
+        assert_eq!(result, "24933642");
+    }
+
+    #[test]
+    fn test_too_deep() {
+        let input = "$ cd a\n".repeat(MAX_DEPTH + 1);
+        let error = Day07::parse(&input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (MAX_DEPTH + 1, 6));
+        assert_eq!(error.message, "directories nest more than 1000 deep");
+    }
+}
@@ -0,0 +1,123 @@
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError, Source};
+use crate::geometry::Direction;
+use crate::grid::{Grid, Pos};
+use crate::params::NoParams;
+use crate::solution::{self, Part, Solution};
+
+fn read(input: &str) -> Result<Grid<u8>, ParseError> {
+    let source = Source::new(Day08::DAY, input);
+    Grid::parse(&source, input, "digit", |c| c.to_digit(10).map(|d| d as u8))
+}
+
+fn is_visible(forest: &Grid<u8>, pos: Pos) -> bool {
+    let height = forest[pos];
+    Direction::ALL.into_iter().any(|direction| {
+        forest
+            .ray(pos, direction.vector().into())
+            .all(|tree| forest[tree] < height)
+    })
+}
+
+fn count_visible(forest: &Grid<u8>) -> usize {
+    forest
+        .positions()
+        .filter(|&pos| is_visible(forest, pos))
+        .count()
+}
+
+fn calculate_view(forest: &Grid<u8>, pos: Pos) -> usize {
+    let height = forest[pos];
+    Direction::ALL
+        .into_iter()
+        .map(|direction| {
+            let mut total = 0;
+            for tree in forest.ray(pos, direction.vector().into()) {
+                total += 1;
+                if forest[tree] >= height {
+                    break;
+                }
+            }
+            total
+        })
+        .product()
+}
+
+fn best_view(forest: &Grid<u8>) -> usize {
+    forest
+        .positions()
+        .map(|pos| calculate_view(forest, pos))
+        .max()
+        .expect("a parsed forest has at least one tree")
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Input = Grid<u8>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(forest: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let result = count_visible(forest);
+        Ok(result.into())
+    }
+
+    fn part2(forest: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        let result = best_view(forest);
+        Ok(result.into())
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day08>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day08>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "21");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, "8");
+    }
+
+    #[test]
+    fn test_single_column() {
+        assert_eq!(part1("1\n2\n3").unwrap(), "3");
+        assert_eq!(part2("1\n2\n3").unwrap(), "0");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day08::parse("303\n2x5").unwrap_err();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "x"));
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError, ReadError, Source};
+use crate::geometry::{Direction, Point};
+use crate::input::Lines;
+use crate::params::NoParams;
+use crate::solution::{self, Part, Solution};
+
+#[derive(Clone, Hash, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct Knot {
+    pub point: Point<i32>,
+    pub parent: Option<Box<Knot>>,
+}
+
+impl Knot {
+    pub fn new(parent: Option<Box<Knot>>) -> Self {
+        Self {
+            parent,
+            ..Default::default()
+        }
+    }
+
+    pub fn rope(length: usize) -> Self {
+        let head = Knot::new(None);
+        let mut next = head;
+
+        for _ in 1..length {
+            let tail = Knot::new(Some(Box::new(next)));
+            next = tail;
+        }
+
+        next
+    }
+
+    /// Moves the head knot one step, returning where this knot ends up if it
+    /// had to move.
+    pub fn mov(&mut self, dir: Direction) -> Option<Point<i32>> {
+        match &mut self.parent {
+            None => {
+                self.point += dir.vector();
+                Some(self.point)
+            }
+            Some(parent) => {
+                let head = parent.mov(dir)?;
+                if head.is_adjacent(&self.point) {
+                    return None;
+                }
+                self.point += (head - self.point).signum();
+                Some(self.point)
+            }
+        }
+    }
+}
+
+/// The most steps the head may take in total. Every step is simulated and the
+/// tail's positions are kept, so this bounds both time and memory. Streamed
+/// input only holds the tail's positions, so there it bounds each move.
+const MAX_STEPS: usize = 10_000_000;
+
+fn read(input: &str) -> Result<Vec<(Direction, usize)>, ParseError> {
+    let source = Source::new(Day09::DAY, input);
+    let mut steps = 0_usize;
+
+    input
+        .lines()
+        .map(|line| {
+            let (dir, val, n) = parse_move(&source, line)?;
+            steps = steps.saturating_add(n);
+            if steps > MAX_STEPS {
+                return Err(source.error(val, format!("more than {} steps in total", MAX_STEPS)));
+            }
+            Ok((dir, n))
+        })
+        .collect()
+}
+
+/// Parses a line such as `R 4`, also returning the text of the step count.
+fn parse_move<'a>(
+    source: &Source,
+    line: &'a str,
+) -> Result<(Direction, &'a str, usize), ParseError> {
+    let (dir, val) = source.split_once(line, " ")?;
+    let dir = match dir {
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        _ => return Err(source.error(dir, "expected U, D, L or R")),
+    };
+    let n = source.parse::<usize>(val)?;
+    Ok((dir, val, n))
+}
+
+/// A rope pulled around by its head, remembering where its tail has been.
+struct Rope {
+    tail: Knot,
+    visited: HashSet<Point<i32>>,
+}
+
+impl Rope {
+    fn new(length: usize) -> Self {
+        let tail = Knot::rope(length);
+        let visited = HashSet::from([tail.point]);
+        Self { tail, visited }
+    }
+
+    fn pull(&mut self, dir: Direction, steps: usize) {
+        for _ in 0..steps {
+            if let Some(point) = self.tail.mov(dir) {
+                self.visited.insert(point);
+            }
+        }
+    }
+}
+
+fn rope_length(part: Part) -> usize {
+    match part {
+        Part::One => 2,
+        Part::Two => 10,
+    }
+}
+
+fn sim_rope(moves: &[(Direction, usize)], length: usize) -> Answer {
+    let mut rope = Rope::new(length);
+    for &(dir, val) in moves {
+        rope.pull(dir, val);
+    }
+
+    rope.visited.len().into()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Input = Vec<(Direction, usize)>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(moves: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        Ok(sim_rope(moves, rope_length(Part::One)))
+    }
+
+    fn part2(moves: &Self::Input, _: &Self::Params) -> Result<Answer, DayError> {
+        Ok(sim_rope(moves, rope_length(Part::Two)))
+    }
+
+    /// Applies each move as it's read. Only the tail's positions are kept, so
+    /// there's no limit on the total number of steps, just on each move and
+    /// on how far the head may stray.
+    fn solve_reader(
+        reader: &mut dyn BufRead,
+        part: Part,
+        _: &Self::Params,
+    ) -> Result<Answer, ReadError> {
+        let mut lines = Lines::new(reader);
+        let mut rope = Rope::new(rope_length(part));
+        let mut head = Point::<i64>::default();
+
+        while let Some((number, line)) = lines.next_line()? {
+            let source = Source::new(Self::DAY, line);
+            let (dir, val, n) = parse_move(&source, line).map_err(|e| e.on_line(number))?;
+            if n > MAX_STEPS {
+                let error = source.error(val, format!("more than {} steps in one move", MAX_STEPS));
+                return Err(error.on_line(number).into());
+            }
+
+            head += dir.vector::<i64>() * n as i64;
+            if head.x.abs().max(head.y.abs()) > i64::from(i32::MAX) {
+                let error = source.error(val, "the head moves too far from the start");
+                return Err(error.on_line(number).into());
+            }
+            rope.pull(dir, n);
+        }
+
+        Ok(rope.visited.len().into())
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day09>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day09>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    const INPUT_LARGE: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "13");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, "1");
+    }
+
+    #[test]
+    fn test_part2_large() {
+        let result = part2(INPUT_LARGE).unwrap();
+
+        assert_eq!(result, "36");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day09::parse("R 4\nX 4").unwrap_err();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "X"));
+        assert_eq!(error.message, "expected U, D, L or R");
+
+        let error = Day09::parse("R 4\nU 18446744073709551615").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.message, "more than 10000000 steps in total");
+    }
+
+    #[test]
+    fn test_stream() {
+        for part in Part::ALL {
+            let streamed =
+                Day09::solve_reader(&mut INPUT_LARGE.as_bytes(), part, &NoParams {}).unwrap();
+
+            assert_eq!(
+                streamed,
+                solution::solve::<Day09>(INPUT_LARGE, part).unwrap()
+            );
+        }
+
+        let error = Day09::solve_reader(&mut "R 4\nU 10000001".as_bytes(), Part::One, &NoParams {})
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 09, line 2, column 3: more than 10000000 steps in one move (\"10000001\")"
+        );
+    }
+}
@@ -0,0 +1,559 @@
+use serde::{Deserialize, Serialize};
+
+use crate::answer::{Answer, Bitmap};
+use crate::error::{DayError, ParseError, SolveError, Source};
+use crate::grid::Grid;
+use crate::ocr;
+use crate::params::Params;
+use crate::pattern::Pattern;
+use crate::solution::{self, Part, Solution};
+
+#[derive(Debug)]
+struct State {
+    // Each instruction moves x by at most an i32, so it would take more than
+    // 2^95 of them to overflow this, far more than a usize can count.
+    pub x: i128,
+    pub row: usize,
+    pub col: usize,
+    pub cycle: i64,
+    pub pixels: Grid<bool>,
+}
+
+impl State {
+    fn new(crt: &Crt) -> Self {
+        Self {
+            x: 1,
+            row: 0,
+            col: 0,
+            cycle: 1,
+            pixels: Grid::filled(crt.width, crt.height, false),
+        }
+    }
+
+    pub fn signal_strength(&self) -> i128 {
+        self.x * i128::from(self.cycle)
+    }
+
+    fn mov(&mut self) {
+        self.col += 1;
+        if self.col == self.pixels.width() {
+            self.col = 0;
+            self.row += 1;
+            if self.row == self.pixels.height() {
+                self.row = 0;
+            }
+        }
+    }
+
+    fn draw(&mut self) {
+        if self.x >= (self.col as i128) - 1 && self.x <= (self.col as i128) + 1 {
+            self.pixels[(self.col, self.row)] = true;
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.draw();
+        self.mov();
+    }
+
+    pub fn bitmap(&self) -> Bitmap {
+        self.pixels.clone().into()
+    }
+}
+
+trait Task {
+    /// Returns true when done
+    fn tick(&mut self, state: &mut State) -> bool;
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum Operation {
+    Noop,
+    Addx(i32),
+}
+
+struct AddxTask {
+    ticks: usize,
+    arg: i32,
+}
+
+impl AddxTask {
+    pub fn new(arg: i32) -> Self {
+        Self { ticks: 1, arg }
+    }
+}
+
+impl Task for AddxTask {
+    fn tick(&mut self, state: &mut State) -> bool {
+        state.cycle += 1;
+        if self.ticks > 0 {
+            self.ticks -= 1;
+            false
+        } else {
+            self.ticks = 0;
+            state.x += i128::from(self.arg);
+            true
+        }
+    }
+}
+
+struct NoopTask;
+
+impl Task for NoopTask {
+    fn tick(&mut self, state: &mut State) -> bool {
+        state.cycle += 1;
+        true
+    }
+}
+
+impl From<Operation> for Box<dyn Task> {
+    fn from(op: Operation) -> Self {
+        match op {
+            Operation::Noop => Box::new(NoopTask),
+            Operation::Addx(arg) => Box::new(AddxTask::new(arg)),
+        }
+    }
+}
+
+pub fn read_program(input: &str) -> impl Iterator<Item = Result<Operation, ParseError>> + '_ {
+    let source = Source::new(Day10::DAY, input);
+    let (addx, noop) = (Pattern::new("addx {arg}"), Pattern::new("noop"));
+
+    input.lines().map(move |line| {
+        let op = line.split(' ').next().unwrap_or_default();
+        match op {
+            "addx" => Ok(Operation::Addx(addx.captures(&source, line)?.parse("arg")?)),
+            "noop" => noop.captures(&source, line).map(|_| Operation::Noop),
+            _ => Err(source.error(op, "expected addx or noop")),
+        }
+    })
+}
+
+/// The screen's size and the cycles whose signal strengths part 1 sums:
+/// every `cycle_step` cycles from `first_cycle` up to `last_cycle`. Part 2
+/// reads the screen as letters unless `letters` is off, as for the example's
+/// test pattern.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Crt {
+    pub width: usize,
+    pub height: usize,
+    pub first_cycle: i64,
+    pub cycle_step: i64,
+    pub last_cycle: i64,
+    pub letters: bool,
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Self {
+            width: 40,
+            height: 6,
+            first_cycle: 20,
+            cycle_step: 40,
+            last_cycle: 220,
+            letters: true,
+        }
+    }
+}
+
+/// The widest and tallest screen [`Crt`] allows, well beyond the real one.
+const MAX_SIZE: usize = 1000;
+
+/// The last cycle part 1 may sample. Up to here x stays below 2^52 and the
+/// cycle below 2^20, so the signal strengths can't overflow their sum.
+const MAX_CYCLE: i64 = 1_000_000;
+
+impl Params for Crt {
+    fn validate(&self) -> Result<(), String> {
+        let sizes = 1..=MAX_SIZE;
+        if !sizes.contains(&self.width) || !sizes.contains(&self.height) {
+            return Err(format!(
+                "the screen must be 1 to {} pixels in each direction",
+                MAX_SIZE
+            ));
+        }
+        if self.first_cycle < 1 || self.cycle_step < 1 {
+            return Err("cycles are counted from 1 in steps of at least 1".to_string());
+        }
+        if self.last_cycle > MAX_CYCLE {
+            return Err(format!("the last cycle can be at most {}", MAX_CYCLE));
+        }
+        Ok(())
+    }
+}
+
+impl Crt {
+    fn is_sampled(&self, cycle: i64) -> bool {
+        cycle >= self.first_cycle && (cycle - self.first_cycle) % self.cycle_step == 0
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    type Input = Vec<Operation>;
+    type Params = Crt;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let program = read_program(input).collect::<Result<Vec<_>, _>>()?;
+        if program.is_empty() {
+            return Err(ParseError::input(Self::DAY, "the program is empty"));
+        }
+        Ok(program)
+    }
+
+    fn part1(program: &Self::Input, crt: &Crt) -> Result<Answer, DayError> {
+        let mut ops = program.iter().copied();
+        let mut state = State::new(crt);
+        let mut task: Option<Box<dyn Task>> = None;
+        let mut sum: i128 = 0;
+
+        while state.cycle <= crt.last_cycle {
+            let current = match &mut task {
+                Some(task) => task,
+                None => {
+                    let op = ops.next().ok_or_else(|| {
+                        SolveError::new(
+                            Self::DAY,
+                            Part::One,
+                            format!("the program ends at cycle {}", state.cycle),
+                        )
+                    })?;
+                    task.insert(op.into())
+                }
+            };
+
+            if crt.is_sampled(state.cycle) {
+                sum += state.signal_strength();
+            }
+
+            if current.tick(&mut state) {
+                task = None;
+            }
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part2(program: &Self::Input, crt: &Crt) -> Result<Answer, DayError> {
+        let mut ops = program.iter().copied();
+        let mut state = State::new(crt);
+        let mut task: Box<dyn Task> = ops.next().expect("checked while parsing").into();
+
+        loop {
+            state.tick();
+            let done_with_op = task.tick(&mut state);
+            if done_with_op {
+                if let Some(op) = ops.next() {
+                    task = op.into();
+                } else {
+                    break;
+                }
+            }
+        }
+
+        match crt.letters {
+            true => Ok(read_frame(state.bitmap())?),
+            false => Ok(state.bitmap().into()),
+        }
+    }
+}
+
+/// Reads the letters on the screen. A screen that can't hold a row of
+/// letters, or that is blank, is answered as the picture itself; any other
+/// screen must be readable, and a glyph missing from the font is an error.
+fn read_frame(bitmap: Bitmap) -> Result<Answer, SolveError> {
+    if bitmap.height() != ocr::GLYPH_HEIGHT || bitmap.rows().flatten().all(|&lit| !lit) {
+        return Ok(bitmap.into());
+    }
+
+    ocr::read(&bitmap).map(Answer::from).map_err(|e| {
+        SolveError::new(
+            Day10::DAY,
+            Part::Two,
+            format!("{} in\n{}", e, bitmap.to_string().trim_end()),
+        )
+    })
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day10>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day10>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SIMPLE: &str = "noop
+addx 3
+addx -5";
+
+    const INPUT: &str = "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+    #[test]
+    fn test_simple() {
+        let mut ops = read_program(SIMPLE);
+        let mut state = State::new(&Crt::default());
+        let mut task: Box<dyn Task> = ops.next().unwrap().unwrap().into();
+
+        if task.tick(&mut state) {
+            task = ops.next().unwrap().unwrap().into();
+        }
+        assert_eq!(state.x, 1);
+
+        if task.tick(&mut state) {
+            task = ops.next().unwrap().unwrap().into();
+        }
+        assert_eq!(state.x, 1);
+
+        if task.tick(&mut state) {
+            task = ops.next().unwrap().unwrap().into();
+        }
+        assert_eq!(state.x, 4);
+
+        if task.tick(&mut state) {
+            task = ops.next().unwrap().unwrap().into();
+        }
+        assert_eq!(state.x, 4);
+
+        task.tick(&mut state);
+        assert_eq!(state.x, -1);
+    }
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "13140");
+    }
+
+    #[test]
+    fn test_crt() {
+        let crt = Crt {
+            last_cycle: 60,
+            ..Crt::default()
+        };
+        let result = solution::solve_with::<Day10>(INPUT, Part::One, &crt).unwrap();
+
+        assert_eq!(result, "1560");
+        assert!(Crt { width: 0, ..crt }.validate().is_err());
+        assert!(Crt {
+            height: usize::MAX,
+            ..crt
+        }
+        .validate()
+        .is_err());
+        assert!(Crt {
+            last_cycle: i64::MAX,
+            ..crt
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_read_frame() {
+        let rows = [
+            "#..#.####.#..#.",
+            "#..#.#....#..#.",
+            "####.#.#..#..#.",
+            "#..#.#....#..#.",
+            "#..#.#....#..#.",
+            "#..#.####..##..",
+        ];
+        let pixels = rows.iter().flat_map(|row| row.chars().map(|c| c == '#'));
+        let bitmap = Bitmap::new(15, pixels.collect());
+        let error = read_frame(bitmap.clone()).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("day 10, part 2: unrecognized glyphs at columns 5 in\n#..#.####"));
+
+        let blank = Bitmap::new(15, vec![false; 90]);
+        assert_eq!(read_frame(blank.clone()).unwrap(), Answer::from(blank));
+
+        let short = Bitmap::new(15, vec![true; 75]);
+        assert_eq!(read_frame(short.clone()).unwrap(), Answer::from(short));
+
+        let error = read_frame(Bitmap::new(15, vec![true; 90])).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("day 10, part 2: unrecognized glyphs at columns 0, 5, 10 in\n"));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert!(part2(INPUT).is_err());
+
+        let crt = Crt {
+            letters: false,
+            ..Crt::default()
+        };
+        let result = solution::solve_with::<Day10>(INPUT, Part::Two, &crt).unwrap();
+
+        assert_eq!(
+            result,
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+"
+        );
+    }
+}
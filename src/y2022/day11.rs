@@ -0,0 +1,388 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError, SolveError, Source};
+use crate::params::Params;
+use crate::pattern::Pattern;
+use crate::solution::{self, Part, Solution};
+
+#[derive(Debug)]
+struct Transaction {
+    item: usize,
+    monkey: usize,
+}
+
+#[derive(Clone, Debug)]
+enum Operator {
+    Add,
+    Mpy,
+}
+
+#[derive(Clone, Debug)]
+enum Operand {
+    Literal(usize),
+    Old,
+}
+
+#[derive(Clone, Debug)]
+struct Operation {
+    operator: Operator,
+    operand: Operand,
+}
+
+impl Operation {
+    /// Widened so that neither adding nor multiplying two levels overflows.
+    pub fn eval(&self, old: usize) -> u128 {
+        let operand = match self.operand {
+            Operand::Literal(operand) => operand,
+            Operand::Old => old,
+        };
+
+        match self.operator {
+            Operator::Add => old as u128 + operand as u128,
+            Operator::Mpy => old as u128 * operand as u128,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: VecDeque<usize>,
+    operation: Operation,
+    test: usize,
+    true_monkey: usize,
+    false_monkey: usize,
+    inspections: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let source = Source::new(Day11::DAY, s);
+        let mut lines = s.lines();
+        let mut line = |template: &'static str| {
+            let what = format!("{:?}", template.split(':').next().unwrap_or(template));
+            let line = source.next(&mut lines, s, &what)?;
+            Pattern::new(template).captures(&source, line.trim_start())
+        };
+
+        line("Monkey {id}:")?.parse::<usize>("id")?;
+
+        let items = line("Starting items:{items}")?
+            .get("items")
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| source.parse::<usize>(item))
+            .collect::<Result<VecDeque<_>, _>>()?;
+
+        let operation = line("Operation: new = old {operator} {operand}")?;
+        let operand = match operation.get("operand") {
+            "old" => Operand::Old,
+            _ => Operand::Literal(operation.parse::<usize>("operand")?),
+        };
+        let operator: Operator = match operation.get("operator") {
+            "+" => Operator::Add,
+            "*" => Operator::Mpy,
+            operator => return Err(source.error(operator, "expected + or *")),
+        };
+        let operation = Operation { operator, operand };
+
+        let test = line("Test: divisible by {divisor}")?;
+        let test = match test.parse::<usize>("divisor")? {
+            0 => return Err(source.error(test.get("divisor"), "cannot test divisibility by 0")),
+            divisor => divisor,
+        };
+
+        let true_monkey = line("If true: throw to monkey {target}")?.parse::<usize>("target")?;
+        let false_monkey = line("If false: throw to monkey {target}")?.parse::<usize>("target")?;
+
+        Ok(Self {
+            items,
+            operation,
+            test,
+            true_monkey,
+            false_monkey,
+            inspections: 0,
+        })
+    }
+}
+
+impl Monkey {
+    /// Inspects `item`, or returns `None` if its worry level overflows.
+    fn operate_one(&mut self, item: usize, lcm: Option<usize>) -> Option<Transaction> {
+        let item = self.operation.eval(item);
+        let item = match lcm {
+            None => item / 3,
+            Some(lcm) => item % lcm as u128,
+        };
+        let item = usize::try_from(item).ok()?;
+        let monkey = if item % self.test == 0 {
+            self.true_monkey
+        } else {
+            self.false_monkey
+        };
+
+        self.inspections += 1;
+        Some(Transaction { item, monkey })
+    }
+
+    fn operate(&mut self, lcm: Option<usize>) -> Option<Vec<Transaction>> {
+        let mut txs: Vec<Transaction> = Vec::with_capacity(self.items.len());
+        while !self.items.is_empty() {
+            let item = self.items.pop_front().unwrap();
+            let tx = self.operate_one(item, lcm)?;
+            txs.push(tx);
+        }
+        Some(txs)
+    }
+
+    pub fn receive_item(&mut self, item: usize) {
+        self.items.push_back(item)
+    }
+
+    pub fn inspections(&self) -> usize {
+        self.inspections
+    }
+
+    pub fn test(&self) -> usize {
+        self.test
+    }
+}
+
+fn read(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = input
+        .split("\n\n")
+        .map(|s| Monkey::from_str(s).map_err(|e| e.rebase(input, s)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if monkeys.len() < 2 {
+        return Err(ParseError::input(
+            Day11::DAY,
+            "expected at least two monkeys",
+        ));
+    }
+
+    for (i, monkey) in monkeys.iter().enumerate() {
+        for target in [monkey.true_monkey, monkey.false_monkey] {
+            if target >= monkeys.len() {
+                return Err(ParseError::input(
+                    Day11::DAY,
+                    format!("monkey {} throws to missing monkey {}", i, target),
+                ));
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        b => gcd(b, a % b),
+    }
+}
+
+/// How many rounds each part plays.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rounds {
+    pub part1_rounds: usize,
+    pub part2_rounds: usize,
+}
+
+impl Default for Rounds {
+    fn default() -> Self {
+        Self {
+            part1_rounds: 20,
+            part2_rounds: 10000,
+        }
+    }
+}
+
+/// The most rounds either part may play, ten times the real part 2.
+const MAX_ROUNDS: usize = 100_000;
+
+impl Params for Rounds {
+    fn validate(&self) -> Result<(), String> {
+        let rounds = 1..=MAX_ROUNDS;
+        if !rounds.contains(&self.part1_rounds) || !rounds.contains(&self.part2_rounds) {
+            return Err(format!("each part plays 1 to {} rounds", MAX_ROUNDS));
+        }
+        Ok(())
+    }
+}
+
+fn run(monkeys: &[Monkey], rounds: &Rounds, part: Part) -> Result<Answer, SolveError> {
+    let mut monkeys = monkeys.to_vec();
+    let error = |message| SolveError::new(Day11::DAY, part, message);
+
+    let (rounds, lcm) = if part == Part::One {
+        (rounds.part1_rounds, None)
+    } else {
+        let lcm = monkeys.iter().try_fold(1_usize, |lcm, monkey| {
+            (lcm / gcd(lcm, monkey.test()))
+                .checked_mul(monkey.test())
+                .ok_or_else(|| error("the divisors' common multiple overflows"))
+        })?;
+        (rounds.part2_rounds, Some(lcm))
+    };
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let monkey = &mut monkeys[i];
+            let txs = monkey
+                .operate(lcm)
+                .ok_or_else(|| error("worry level overflows"))?;
+            txs.into_iter().for_each(|tx| {
+                let monkey = &mut monkeys[tx.monkey];
+                monkey.receive_item(tx.item);
+            });
+        }
+    }
+
+    let mut monkey_inspections = monkeys
+        .into_iter()
+        .map(|m| m.inspections())
+        .collect::<Vec<_>>();
+    monkey_inspections.sort();
+    let mut sorted_monkey = monkey_inspections.into_iter().rev();
+
+    let result = sorted_monkey.next().unwrap() as u128 * sorted_monkey.next().unwrap() as u128;
+    i64::try_from(result)
+        .map(Answer::from)
+        .map_err(|_| error("monkey business overflows"))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    type Input = Vec<Monkey>;
+    type Params = Rounds;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(monkeys: &Self::Input, rounds: &Rounds) -> Result<Answer, DayError> {
+        Ok(run(monkeys, rounds, Part::One)?)
+    }
+
+    fn part2(monkeys: &Self::Input, rounds: &Rounds) -> Result<Answer, DayError> {
+        Ok(run(monkeys, rounds, Part::Two)?)
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day11>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day11>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn test_part1() {
+        let result = part1(INPUT).unwrap();
+
+        assert_eq!(result, "10605");
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, "2713310158");
+    }
+
+    #[test]
+    fn test_rounds() {
+        let rounds = Rounds {
+            part1_rounds: 1,
+            part2_rounds: 20,
+        };
+
+        let result = solution::solve_with::<Day11>(INPUT, Part::One, &rounds).unwrap();
+        assert_eq!(result, "20");
+        let result = solution::solve_with::<Day11>(INPUT, Part::Two, &rounds).unwrap();
+        assert_eq!(result, "10197");
+        assert!(Rounds {
+            part1_rounds: 0,
+            ..rounds
+        }
+        .validate()
+        .is_err());
+        assert!(Rounds {
+            part2_rounds: usize::MAX,
+            ..rounds
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("divisible by 19", "divisible by x");
+        let error = Day11::parse(&input).unwrap_err();
+
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (11, 22, "x")
+        );
+
+        let input = INPUT.replace("throw to monkey 0", "throw to monkey 4");
+        let error = Day11::parse(&input).unwrap_err();
+
+        assert_eq!(error.message, "monkey 1 throws to missing monkey 4");
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = INPUT.replace("79, 98", "18446744073709551615");
+
+        assert_eq!(
+            part1(&input).unwrap_err().to_string(),
+            "day 11, part 1: worry level overflows"
+        );
+        assert!(part2(&input).is_ok());
+    }
+}
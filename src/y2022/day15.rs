@@ -0,0 +1,183 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::error::{DayError, ParseError};
+use crate::from_pattern;
+use crate::geometry::Point;
+use crate::params::Params;
+use crate::pattern::Captures;
+use crate::range_set::{Interval, RangeSet};
+use crate::solution::{self, Part, Solution};
+
+#[derive(Debug)]
+pub struct Sensor {
+    location: Point,
+    closest_beacon: Point,
+}
+
+/// Far beyond any real scan, but small enough that distances between points
+/// can't overflow.
+const MAX_COORDINATE: i64 = 1_000_000_000_000_000;
+
+fn coordinate(captures: &Captures, name: &str) -> Result<i64, ParseError> {
+    match captures.parse::<i64>(name)? {
+        n if n.unsigned_abs() <= MAX_COORDINATE.unsigned_abs() => Ok(n),
+        _ => Err(captures.error(
+            name,
+            format!("coordinates must be within ±{}", MAX_COORDINATE),
+        )),
+    }
+}
+
+from_pattern!(
+    Day15::DAY,
+    Sensor,
+    "Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}",
+    |c| Sensor {
+        location: Point::new(coordinate(&c, "sx")?, coordinate(&c, "sy")?),
+        closest_beacon: Point::new(coordinate(&c, "bx")?, coordinate(&c, "by")?),
+    }
+);
+
+fn read(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    input
+        .lines()
+        .map(|line| Sensor::from_str(line).map_err(|e| e.rebase(input, line)))
+        .collect()
+}
+
+impl Sensor {
+    /// The positions in `row` that are no further from the sensor than its
+    /// closest beacon.
+    fn coverage(&self, row: i64) -> Option<Interval> {
+        let radius = self.location.manhattan(&self.closest_beacon);
+        let reach = radius - (self.location.y - row).abs();
+        (reach >= 0).then(|| Interval::new(self.location.x - reach, self.location.x + reach))
+    }
+}
+
+fn non_beacons_in_row(sensors: &[Sensor], row: i64) -> Answer {
+    let covered = sensors
+        .iter()
+        .filter_map(|sensor| sensor.coverage(row))
+        .collect::<RangeSet>();
+    // Sensors and beacons themselves are not counted.
+    let occupied = sensors
+        .iter()
+        .flat_map(|sensor| [sensor.location, sensor.closest_beacon])
+        .filter(|point| point.y == row)
+        .map(|point| Interval::point(point.x))
+        .collect::<RangeSet>();
+
+    covered.difference(&occupied).len().into()
+}
+
+/// Where to look for beacons.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Scan {
+    /// The row part 1 counts positions in.
+    pub row: i64,
+}
+
+impl Default for Scan {
+    fn default() -> Self {
+        Self { row: 2000000 }
+    }
+}
+
+impl Params for Scan {
+    fn validate(&self) -> Result<(), String> {
+        match self.row.unsigned_abs() <= MAX_COORDINATE.unsigned_abs() {
+            true => Ok(()),
+            false => Err(format!("the row must be within ±{}", MAX_COORDINATE)),
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+
+    type Input = Vec<Sensor>;
+    type Params = Scan;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(sensors: &Self::Input, scan: &Scan) -> Result<Answer, DayError> {
+        Ok(non_beacons_in_row(sensors, scan.row))
+    }
+
+    fn part2(_sensors: &Self::Input, _: &Scan) -> Result<Answer, DayError> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day15>(input, Part::One)
+}
+
+pub fn part2(input: &str) -> Result<Answer, DayError> {
+    solution::solve::<Day15>(input, Part::Two)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+    #[test]
+    fn test_part1() {
+        let scan = Scan { row: 10 };
+        let result = solution::solve_with::<Day15>(INPUT, Part::One, &scan).unwrap();
+
+        assert_eq!(result, "26");
+        assert!(Scan { row: i64::MIN }.validate().is_err());
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(INPUT).unwrap();
+
+        assert_eq!(result, Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day15::parse("Sensor at x=2, y=18: closest beacon is at x=-2").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 47));
+        assert_eq!(error.message, "expected \", y=\"");
+
+        let error =
+            Day15::parse("Sensor at x=2, y=18: closest beacon is at x=-9223372036854775808, y=15")
+                .unwrap_err();
+        assert_eq!((error.line, error.column), (1, 45));
+        assert_eq!(
+            error.message,
+            "coordinates must be within ±1000000000000000"
+        );
+    }
+}
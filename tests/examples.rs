@@ -0,0 +1,13 @@
+//! Runs every registered day on the examples in `fixtures/<year>/NN.toml`,
+//! which `aoc examples` extracts from the cached puzzle descriptions. Days
+//! without a fixture are skipped and listed on stderr.
+
+use aoc_2021::checks;
+use aoc_2021::config::Config;
+
+#[test]
+fn test_examples() {
+    let solvers = aoc_2021::days().collect::<Vec<_>>();
+
+    checks::examples(&Config::default(), &solvers).assert_passed();
+}
@@ -0,0 +1,23 @@
+//! Runs every registered day on each of its committed input profiles and
+//! checks the answers recorded in `answers.toml`. Days without recorded
+//! answers or without an input are skipped and listed on stderr.
+
+use aoc_2021::answers::KnownAnswers;
+use aoc_2021::checks;
+use aoc_2021::config::Config;
+
+#[test]
+fn test_known_answers() {
+    let known = KnownAnswers::load(&KnownAnswers::default_path()).unwrap();
+    let solvers = aoc_2021::days().collect::<Vec<_>>();
+
+    checks::known_answers(&Config::default(), &known, &solvers).assert_passed();
+}
+
+#[test]
+fn test_streamed_answers() {
+    let known = KnownAnswers::load(&KnownAnswers::default_path()).unwrap();
+    let solvers = aoc_2021::days().collect::<Vec<_>>();
+
+    checks::streamed_answers(&Config::default(), &known, &solvers).assert_passed();
+}